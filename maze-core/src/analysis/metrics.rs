@@ -0,0 +1,152 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    point::{grid::Grid, point::Point},
+    solve::bfs::bfs_tree,
+    tools::{
+        math::get_dist,
+        matrix::get_connected_cells,
+        storage::MazeStorage,
+    },
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MazeMetrics {
+    // Passage cells on odd coordinates
+    pub cells: usize,
+    pub dead_ends: usize,
+    // Amount of cells for every number of openings, index 3 and 4 are the junctions
+    pub degree_counts: [usize; 5],
+    // Corridor length in cells -> how many corridors have that length
+    pub corridor_lengths: BTreeMap<usize, usize>,
+    // Average corridor length in cells, how far the passages wind on before they branch or end
+    pub river_factor: f64,
+    // Independent cycles of the passage graph: edges - vertices + components
+    pub loops: usize,
+    pub components: usize,
+    // Cell steps from start to end, None if there is no solution
+    pub solution_length: Option<u64>,
+    pub solution_fraction: f64,
+    // Solution length divided by the manhattan distance between start and end
+    pub tortuosity: f64,
+}
+
+impl MazeMetrics {
+    pub fn junctions(&self) -> usize {
+        self.degree_counts[3] + self.degree_counts[4]
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+pub fn analyze<M: MazeStorage>(maze: &M, start: &Point, end: &Point) -> Result<MazeMetrics> {
+    let size = maze.get_size();
+    let mut metrics = MazeMetrics::default();
+
+    let mut cells = Vec::new();
+    let mut degrees = Grid::new(size, None);
+    for y in (1..size.height).step_by(2) {
+        for x in (1..size.width).step_by(2) {
+            let p = Point { x, y };
+            if !maze.is_passage(&p) {
+                continue;
+            }
+
+            let degree = get_connected_cells(maze, &p)?.len();
+            degrees[&p] = Some(degree);
+            metrics.degree_counts[degree] += 1;
+            cells.push(p);
+        }
+    }
+
+    metrics.cells = cells.len();
+    metrics.dead_ends = metrics.degree_counts[1];
+
+    let edges: usize = metrics.degree_counts.iter().enumerate().map(|(degree, count)| degree * count).sum::<usize>() / 2;
+    metrics.components = count_components(maze, &cells)?;
+    metrics.loops = (edges + metrics.components).saturating_sub(metrics.cells);
+
+    metrics.corridor_lengths = corridor_lengths(maze, &cells, &degrees)?;
+    let corridors: usize = metrics.corridor_lengths.values().sum();
+    if corridors > 0 {
+        metrics.river_factor = metrics.degree_counts[2] as f64 / corridors as f64;
+    }
+
+    let is_cell = |p: &Point| degrees[p].is_some();
+    if is_cell(start) && is_cell(end) {
+        metrics.solution_length = bfs_tree(maze, start)?.distance_to(end);
+    }
+
+    if let Some(length) = metrics.solution_length {
+        metrics.solution_fraction = (length + 1) as f64 / metrics.cells as f64;
+
+        let direct = get_dist(start, end) / 2;
+        if direct > 0 {
+            metrics.tortuosity = length as f64 / direct as f64;
+        }
+    }
+
+    Ok(metrics)
+}
+
+fn count_components<M: MazeStorage>(maze: &M, cells: &[Point]) -> Result<usize> {
+    let size = maze.get_size();
+    let mut visited = Grid::new(size, false);
+    let mut components = 0;
+    for cell in cells {
+        if visited[cell] {
+            continue;
+        }
+
+        components += 1;
+        visited[cell] = true;
+
+        let mut pending = VecDeque::from([*cell]);
+        while let Some(p) = pending.pop_front() {
+            for n in get_connected_cells(maze, &p)? {
+                if !visited[&n] {
+                    visited[&n] = true;
+                    pending.push_back(n);
+                }
+            }
+        }
+    }
+
+    Ok(components)
+}
+
+// A corridor is a maximal chain of cells that have exactly two openings
+fn corridor_lengths<M: MazeStorage>(maze: &M, cells: &[Point], degrees: &Grid<Option<usize>>) -> Result<BTreeMap<usize, usize>> {
+    let size = maze.get_size();
+    let mut visited = Grid::new(size, false);
+    let mut lengths = BTreeMap::new();
+    for cell in cells {
+        if visited[cell] || degrees[cell] != Some(2) {
+            continue;
+        }
+
+        visited[cell] = true;
+        let mut length = 1;
+        let mut pending = vec![*cell];
+        while let Some(p) = pending.pop() {
+            for n in get_connected_cells(maze, &p)? {
+                if visited[&n] || degrees[&n] != Some(2) {
+                    continue;
+                }
+
+                visited[&n] = true;
+                length += 1;
+                pending.push(n);
+            }
+        }
+
+        *lengths.entry(length).or_insert(0) += 1;
+    }
+
+    Ok(lengths)
+}
@@ -0,0 +1,2 @@
+pub mod metrics;
+pub mod validate;
\ No newline at end of file
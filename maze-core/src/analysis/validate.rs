@@ -0,0 +1,138 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    point::{bit_grid::BitGrid, point::Point},
+    tools::{
+        matrix::get_connected_cells,
+        storage::MazeStorage,
+    },
+};
+
+// Cells that are connected by passages, only the first one in row order is kept to point at the region
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    pub first: Point,
+    pub cells: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Validation {
+    // Exactly one path between any two cells
+    pub perfect: bool,
+    // Independent cycles of the passage graph, zero for a perfect maze
+    pub loops: usize,
+    // Cells grouped by the passages that connect them, a connected maze has a single region
+    pub regions: Vec<Region>,
+    // Passages between four cells, the cell model only ever carves odd coordinates and the points between them
+    pub even_passages: Vec<Point>,
+    // Passages on the outer border
+    pub border_breaks: Vec<Point>,
+}
+
+impl Validation {
+    pub fn is_connected(&self) -> bool {
+        self.regions.len() <= 1
+    }
+
+    // Everything except loops is fine, which is what decimated or imported mazes look like
+    pub fn is_well_formed(&self) -> bool {
+        self.is_connected() && self.even_passages.is_empty() && self.border_breaks.is_empty()
+    }
+
+    // Same as is_well_formed, but the border may be opened up at or right next to the start and end,
+    // which is what Placement::Openings does. Used by every importer so saved mazes can be opened again.
+    pub fn is_well_formed_with(&self, markers: Option<(Point, Point)>) -> bool {
+        let is_opening = |p: &Point| markers.is_some_and(|(start, end)| {
+            [start, end].iter().any(|e| e.x.abs_diff(p.x) + e.y.abs_diff(p.y) <= 1)
+        });
+
+        self.is_connected() && self.even_passages.is_empty() && self.border_breaks.iter().all(is_opening)
+    }
+}
+
+impl Display for Validation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.perfect && self.border_breaks.is_empty() {
+            return write!(f, "Maze is perfect");
+        }
+
+        let mut problems = Vec::new();
+        if self.loops > 0 {
+            problems.push(format!("{} loops", self.loops));
+        }
+        if !self.is_connected() {
+            problems.push(format!("{} disconnected regions", self.regions.len()));
+        }
+        if !self.even_passages.is_empty() {
+            problems.push(format!("{} passages on even coordinates, first at {}", self.even_passages.len(), self.even_passages[0]));
+        }
+        if !self.border_breaks.is_empty() {
+            problems.push(format!("{} holes in the border, first at {}", self.border_breaks.len(), self.border_breaks[0]));
+        }
+
+        write!(f, "Maze is not perfect: {}", problems.join(", "))
+    }
+}
+
+pub fn validate<M: MazeStorage>(maze: &M) -> Result<Validation> {
+    let size = maze.get_size();
+    if !size.is_valid() {
+        return Err(anyhow!("Size {} is invalid, both sides have to be odd and at least 3", size));
+    }
+
+    let mut validation = Validation::default();
+    for y in 0..size.height {
+        for x in 0..size.width {
+            let p = Point { x, y };
+            if !maze.is_passage(&p) {
+                continue;
+            }
+
+            if size.is_border(&p) {
+                validation.border_breaks.push(p);
+            } else if x.is_multiple_of(2) && y.is_multiple_of(2) {
+                validation.even_passages.push(p);
+            }
+        }
+    }
+
+    let mut visited = BitGrid::new(size, false);
+    let mut cells = 0;
+    let mut edges = 0;
+    for y in (1..size.height).step_by(2) {
+        for x in (1..size.width).step_by(2) {
+            let cell = Point { x, y };
+            if !maze.is_passage(&cell) || visited.get(&cell) {
+                continue;
+            }
+
+            visited.set(&cell, true);
+            let mut region = Region { first: cell, cells: 1 };
+            let mut pending = vec![cell];
+            while let Some(p) = pending.pop() {
+                let connected = get_connected_cells(maze, &p)?;
+                edges += connected.len();
+
+                for n in connected {
+                    if !visited.get(&n) {
+                        visited.set(&n, true);
+                        region.cells += 1;
+                        pending.push(n);
+                    }
+                }
+            }
+
+            cells += region.cells;
+            validation.regions.push(region);
+        }
+    }
+
+    // Every edge was seen from both of its cells
+    let edges = edges / 2;
+    validation.loops = (edges + validation.regions.len()).saturating_sub(cells);
+    validation.perfect = validation.loops == 0 && validation.is_connected() && validation.even_passages.is_empty();
+
+    Ok(validation)
+}
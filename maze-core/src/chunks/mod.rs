@@ -0,0 +1,5 @@
+mod tile;
+mod world;
+
+pub use tile::*;
+pub use world::*;
\ No newline at end of file
@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    generators::{decimate::decimate_maze, generate::generate},
+    point::{point::Point, point_state::PointState},
+    tools::{consts::{Maze, MazeOptions}, math::mix_seed, options::{AnimOptions, MazeData}},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkOptions {
+    // Points per side of a tile including both borders, neighbouring tiles share their border
+    pub tile_size: usize,
+    // Openings in every border between two tiles
+    pub doors: usize,
+    pub decimate: usize,
+}
+
+impl Default for ChunkOptions {
+    fn default() -> Self {
+        Self { tile_size: 31, doors: 1, decimate: 0 }
+    }
+}
+
+impl ChunkOptions {
+    // Distance between the left borders of two neighbouring tiles
+    pub fn get_period(&self) -> usize {
+        self.tile_size - 1
+    }
+
+    pub fn check(&self) -> Result<()> {
+        if self.tile_size < 5 || self.tile_size.is_multiple_of(2) {
+            return Err(anyhow!("Tile size has to be odd and at least 5, got {}", self.tile_size));
+        }
+
+        let cells = self.tile_size / 2;
+        if self.doors == 0 || self.doors > cells {
+            return Err(anyhow!("Every tile border needs between 1 and {} doors, got {}", cells, self.doors));
+        }
+
+        Ok(())
+    }
+}
+
+// Position of a tile in the world, the world goes on forever in every direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub struct TilePos {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl TilePos {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn offset(&self, x: i64, y: i64) -> Self {
+        Self { x: self.x + x, y: self.y + y }
+    }
+}
+
+impl Display for TilePos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    Left,
+    Top,
+}
+
+// Every tile gets its own seed, so tiles can be generated in any order and always look the same
+pub fn tile_seed(seed: u64, pos: TilePos) -> u64 {
+    mix_seed(seed, &[pos.x as u64, pos.y as u64, 0])
+}
+
+fn edge_seed(seed: u64, pos: TilePos, edge: Edge) -> u64 {
+    let id = match edge {
+        Edge::Left => 1,
+        Edge::Top => 2,
+    };
+
+    mix_seed(seed, &[pos.x as u64, pos.y as u64, id])
+}
+
+// Offsets along the left or top border of the tile that are opened up. The tile on the other side
+// sees the same border as its right or bottom one, so both sides always agree on the doors.
+fn edge_doors(seed: u64, options: &ChunkOptions, pos: TilePos, edge: Edge) -> Vec<usize> {
+    let mut seeder = StdRng::seed_from_u64(edge_seed(seed, pos, edge));
+    let cells: Vec<usize> = (1..options.get_period()).step_by(2).collect();
+
+    cells.choose_multiple(&mut seeder, options.doors).copied().collect()
+}
+
+// Generates a single tile with the doors to all four of its neighbours already opened
+pub fn generate_tile(seed: u64, options: &ChunkOptions, pos: TilePos) -> Result<Maze> {
+    options.check()?;
+
+    let opt = MazeOptions::new(options.tile_size, tile_seed(seed, pos), options.decimate);
+    let data = MazeData::headless(&opt, &AnimOptions::new(false, false, 1.0));
+    let mut maze = generate(&data)?;
+    if options.decimate > 0 {
+        decimate_maze(&data, &mut maze)?;
+    }
+
+    let last = options.tile_size - 1;
+    for y in edge_doors(seed, options, pos, Edge::Left) {
+        maze[Point { x: 0, y }] = PointState::Passage;
+    }
+    for y in edge_doors(seed, options, pos.offset(1, 0), Edge::Left) {
+        maze[Point { x: last, y }] = PointState::Passage;
+    }
+    for x in edge_doors(seed, options, pos, Edge::Top) {
+        maze[Point { x, y: 0 }] = PointState::Passage;
+    }
+    for x in edge_doors(seed, options, pos.offset(0, 1), Edge::Top) {
+        maze[Point { x, y: last }] = PointState::Passage;
+    }
+
+    Ok(maze)
+}
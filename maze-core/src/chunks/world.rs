@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::{
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    tools::consts::Maze,
+};
+
+use super::tile::{generate_tile, ChunkOptions, TilePos};
+
+// Tiles kept in memory if no other limit is set
+pub const DEFAULT_TILE_CAPACITY: usize = 1024;
+
+struct CachedTile {
+    maze: Maze,
+    // Value of the use counter when the tile was looked at the last time
+    last_used: u64,
+}
+
+// Endless maze that is split up into tiles, which are only generated once something looks at them.
+// World coordinates can be negative, the point (0, 0) is the top left corner of the tile (0, 0).
+// Only the most recently used tiles are kept, the others are generated again when they are needed.
+pub struct ChunkedMaze {
+    seed: u64,
+    options: ChunkOptions,
+    tiles: HashMap<TilePos, CachedTile>,
+    capacity: usize,
+    uses: u64,
+}
+
+impl ChunkedMaze {
+    pub fn new(seed: u64, options: ChunkOptions) -> Result<Self> {
+        options.check()?;
+
+        Ok(Self { seed, options, tiles: HashMap::new(), capacity: DEFAULT_TILE_CAPACITY, uses: 0 })
+    }
+
+    pub fn get_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    // Drops the least recently used tiles right away if there are more than the new capacity
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        self.evict();
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn get_options(&self) -> ChunkOptions {
+        self.options
+    }
+
+    // Amount of tiles that are currently kept in memory
+    pub fn loaded_tiles(&self) -> usize {
+        self.tiles.len()
+    }
+
+    // Tile the world point belongs to and the position inside of that tile
+    pub fn locate(&self, x: i64, y: i64) -> (TilePos, Point) {
+        let period = self.options.get_period() as i64;
+        let tile = TilePos::new(x.div_euclid(period), y.div_euclid(period));
+        let local = Point { x: x.rem_euclid(period) as usize, y: y.rem_euclid(period) as usize };
+
+        (tile, local)
+    }
+
+    // World position of the top left corner of the tile
+    pub fn tile_origin(&self, pos: TilePos) -> (i64, i64) {
+        let period = self.options.get_period() as i64;
+        (pos.x * period, pos.y * period)
+    }
+
+    pub fn is_loaded(&self, pos: TilePos) -> bool {
+        self.tiles.contains_key(&pos)
+    }
+
+    pub fn get_tile(&mut self, pos: TilePos) -> Result<&Maze> {
+        if !self.tiles.contains_key(&pos) {
+            let tile = generate_tile(self.seed, &self.options, pos)?;
+            self.insert_tile(pos, tile);
+        }
+
+        Ok(self.peek_tile(pos).unwrap())
+    }
+
+    // Tile if it is loaded, nothing is generated
+    pub fn peek_tile(&mut self, pos: TilePos) -> Option<&Maze> {
+        self.uses += 1;
+        let uses = self.uses;
+        self.tiles.get_mut(&pos).map(|e| {
+            e.last_used = uses;
+            &e.maze
+        })
+    }
+
+    // Adds a tile that was generated somewhere else, e.g. on another thread with `generate_tile`
+    pub fn insert_tile(&mut self, pos: TilePos, maze: Maze) {
+        self.uses += 1;
+        self.tiles.insert(pos, CachedTile { maze, last_used: self.uses });
+        self.evict();
+    }
+
+    pub fn get_state(&mut self, x: i64, y: i64) -> Result<PointState> {
+        let (tile, local) = self.locate(x, y);
+        Ok(self.get_tile(tile)?[local])
+    }
+
+    // Tiles that cover the part of the world with its top left corner at (x, y)
+    pub fn tiles_in(&self, x: i64, y: i64, size: Size) -> Vec<TilePos> {
+        let (min, _) = self.locate(x, y);
+        let (max, _) = self.locate(x + size.width as i64 - 1, y + size.height as i64 - 1);
+
+        let mut tiles = Vec::new();
+        for tile_y in min.y..=max.y {
+            for tile_x in min.x..=max.x {
+                tiles.push(TilePos::new(tile_x, tile_y));
+            }
+        }
+
+        tiles
+    }
+
+    // Copies the part of the world with its top left corner at (x, y) into a normal maze. Every tile is copied
+    // as soon as it is loaded, so regions with more tiles than the capacity don't lose the ones evicted meanwhile.
+    pub fn region(&mut self, x: i64, y: i64, size: Size) -> Result<Maze> {
+        let mut region = Grid::new(size, PointState::Wall);
+        let tile_size = self.options.tile_size as i64;
+        for pos in self.tiles_in(x, y, size) {
+            let origin = self.tile_origin(pos);
+            copy_tile(&mut region, self.get_tile(pos)?, tile_size, origin, (x, y));
+        }
+
+        Ok(region)
+    }
+
+    // Same as region, but tiles that aren't loaded stay walls and are returned instead of being generated
+    pub fn loaded_region(&mut self, x: i64, y: i64, size: Size) -> (Maze, Vec<TilePos>) {
+        let mut region = Grid::new(size, PointState::Wall);
+        let tile_size = self.options.tile_size as i64;
+        let mut missing = Vec::new();
+        for pos in self.tiles_in(x, y, size) {
+            let origin = self.tile_origin(pos);
+            let Some(tile) = self.peek_tile(pos) else {
+                missing.push(pos);
+                continue;
+            };
+
+            copy_tile(&mut region, tile, tile_size, origin, (x, y));
+        }
+
+        (region, missing)
+    }
+
+    // Forgets every tile that doesn't satisfy `keep`, they are generated again when needed
+    pub fn retain(&mut self, keep: impl Fn(&TilePos) -> bool) {
+        self.tiles.retain(|pos, _| keep(pos));
+    }
+
+    fn evict(&mut self) {
+        while self.tiles.len() > self.capacity {
+            let oldest = self.tiles.iter().min_by_key(|(_, e)| e.last_used).map(|(pos, _)| *pos).unwrap();
+            self.tiles.remove(&oldest);
+        }
+    }
+}
+
+// Copies the part of the tile at `origin` that is inside of the region with its top left corner at `corner`
+fn copy_tile(region: &mut Maze, tile: &Maze, tile_size: i64, origin: (i64, i64), corner: (i64, i64)) {
+    let ((origin_x, origin_y), (x, y)) = (origin, corner);
+    let size = region.get_size();
+    let (from_x, to_x) = ((x - origin_x).max(0), (x + size.width as i64 - origin_x).min(tile_size));
+    let (from_y, to_y) = ((y - origin_y).max(0), (y + size.height as i64 - origin_y).min(tile_size));
+    for local_y in from_y..to_y {
+        for local_x in from_x..to_x {
+            let p = Point { x: (origin_x + local_x - x) as usize, y: (origin_y + local_y - y) as usize };
+            region[p] = tile[Point { x: local_x as usize, y: local_y as usize }];
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::{collections::HashSet, sync::RwLock};
+
+use anyhow::Result;
+
+use crate::{
+    point::{grid::Grid, sparse_grid::SparseGrid, point::Point, point_state::{PointState, VisualIndicator}, size::Size},
+    tools::{
+        color::Color,
+        consts::{Maze, VisualOverwrites},
+        render::render_maze,
+    },
+};
+
+use super::event::{MazeEvent, MazeObserver};
+
+#[derive(Default)]
+struct CanvasState {
+    maze: Maze,
+    visual_overwrites: VisualOverwrites,
+    frontier: HashSet<Point>,
+    progress: f64,
+    finished: bool,
+}
+
+// Rebuilds the maze and its overlay from events, so it can be rendered whenever the consumer wants to
+#[derive(Default)]
+pub struct Canvas {
+    state: RwLock<CanvasState>,
+}
+
+impl Canvas {
+    pub fn get_size(&self) -> Size {
+        self.state.read().unwrap().maze.get_size()
+    }
+
+    pub fn get_progress(&self) -> f64 {
+        self.state.read().unwrap().progress
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state.read().unwrap().finished
+    }
+
+    pub fn get_maze(&self) -> Maze {
+        self.state.read().unwrap().maze.clone()
+    }
+
+    pub fn render(&self) -> Vec<Color> {
+        let state = self.state.read().unwrap();
+        let mut visual_overwrites = state.visual_overwrites.clone();
+        for p in &state.frontier {
+            if visual_overwrites.contains(p) && visual_overwrites.get(p).is_none() {
+                visual_overwrites.insert(*p, VisualIndicator::Searching);
+            }
+        }
+
+        render_maze(&state.maze, &visual_overwrites)
+    }
+
+    pub fn apply(&self, event: &MazeEvent) {
+        let mut state = self.state.write().unwrap();
+        match event {
+            MazeEvent::Started { size } => {
+                *state = CanvasState {
+                    maze: Grid::new(*size, PointState::Wall),
+                    visual_overwrites: SparseGrid::new(*size),
+                    ..Default::default()
+                };
+            }
+            MazeEvent::Loaded(maze) => {
+                *state = CanvasState {
+                    maze: maze.clone(),
+                    visual_overwrites: SparseGrid::new(maze.get_size()),
+                    progress: 1.0,
+                    ..Default::default()
+                };
+            }
+            MazeEvent::CellCarved(p) => state.maze[p] = PointState::Passage,
+            MazeEvent::CellWalled(p) => state.maze[p] = PointState::Wall,
+            MazeEvent::CellVisited(p, indicator) => state.visual_overwrites.insert(*p, *indicator),
+            MazeEvent::FrontierChanged { added, removed } => {
+                for p in removed {
+                    state.frontier.remove(p);
+                }
+
+                state.frontier.extend(added.iter());
+            }
+            MazeEvent::OverlayCleared => {
+                state.visual_overwrites.clear();
+                state.frontier.clear();
+            }
+            MazeEvent::Progress(progress) => state.progress = *progress,
+            MazeEvent::Finished => state.finished = true,
+            MazeEvent::PathFound(_) | MazeEvent::Step | MazeEvent::Message(_) => {}
+        }
+    }
+}
+
+impl MazeObserver for Canvas {
+    fn on_event(&self, event: &MazeEvent) -> Result<()> {
+        self.apply(event);
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+
+use crate::{
+    point::{point::Point, point_state::VisualIndicator, size::Size},
+    tools::consts::{Maze, VisualOverwrites},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MazeEvent {
+    // A new maze of the given size that only consists of walls
+    Started { size: Size },
+    // A maze that wasn't generated, e.g. loaded from a file, replaces the current one
+    Loaded(Maze),
+    CellCarved(Point),
+    CellWalled(Point),
+    // Point was looked at by an algorithm, the indicator says how it should be shown
+    CellVisited(Point, VisualIndicator),
+    // Cells an algorithm is going to look at next
+    FrontierChanged { added: Vec<Point>, removed: Vec<Point> },
+    // Removes every indicator that was set by CellVisited
+    OverlayCleared,
+    PathFound(Vec<Point>),
+    // Generation progress from 0 to 1
+    Progress(f64),
+    // One step of an algorithm is done, observers that animate can wait here
+    Step,
+    // Status text for observers that log, e.g. which solver runs or how long its route is
+    Message(String),
+    Finished,
+}
+
+// Receives every event of a generator or solver on the thread it is running on.
+// Returning an error aborts the running algorithm.
+pub trait MazeObserver: Send + Sync {
+    fn on_event(&self, event: &MazeEvent) -> Result<()>;
+}
+
+// Events that replace the overlay of observers with the given one
+pub fn overlay_events(visual_overwrites: &VisualOverwrites) -> Vec<MazeEvent> {
+    let mut events = vec![MazeEvent::OverlayCleared];
+    for (p, indicator) in visual_overwrites.iter() {
+        events.push(MazeEvent::CellVisited(p, *indicator));
+    }
+
+    events
+}
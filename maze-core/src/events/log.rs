@@ -0,0 +1,30 @@
+use anyhow::Result;
+
+use super::event::{MazeEvent, MazeObserver};
+
+// Prints progress, messages and found paths to stdout and ignores everything that happens per cell
+#[derive(Default)]
+pub struct LogObserver {
+    pub print_paths: bool,
+}
+
+impl MazeObserver for LogObserver {
+    fn on_event(&self, event: &MazeEvent) -> Result<()> {
+        match event {
+            MazeEvent::Started { size } => println!("Generating {}...", size),
+            MazeEvent::Progress(progress) => println!("Generation: {}%", (progress * 100.0 * 100.0).round() / 100.0),
+            MazeEvent::PathFound(path) => {
+                println!("Found path with {} steps", path.len().saturating_sub(1));
+                if self.print_paths {
+                    let readable = path.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+                    println!("{}", readable.join(" -> "));
+                }
+            }
+            MazeEvent::Message(text) => println!("{}", text),
+            MazeEvent::Finished => println!("Done."),
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
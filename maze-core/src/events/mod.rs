@@ -0,0 +1,4 @@
+pub mod canvas;
+pub mod event;
+pub mod log;
+pub mod recorder;
\ No newline at end of file
@@ -0,0 +1,118 @@
+use std::sync::RwLock;
+
+use anyhow::{anyhow, Result};
+use image::RgbaImage;
+
+use crate::{point::size::Size, tools::{
+    animation::{save_animation, RecordOptions},
+    color::Color,
+    image::pixels_to_img,
+}};
+
+use super::{canvas::Canvas, event::{MazeEvent, MazeObserver}};
+
+struct RecorderState {
+    steps: usize,
+    // Frames are only taken every frame_skip * stride steps, doubled whenever the memory limit is hit
+    stride: usize,
+    bytes: usize,
+    // Frames are kept with one colour per point and only scaled up while they are saved
+    frames: Vec<(Size, Vec<Color>)>,
+}
+
+impl Default for RecorderState {
+    fn default() -> Self {
+        Self { steps: 0, stride: 1, bytes: 0, frames: Vec::new() }
+    }
+}
+
+// Keeps every nth frame of a run in memory so it can be saved as an animation afterwards.
+// Long recordings drop every other frame whenever they would need more than `max_bytes`.
+pub struct Recorder {
+    canvas: Canvas,
+    options: RecordOptions,
+    state: RwLock<RecorderState>,
+}
+
+impl Recorder {
+    pub fn new(options: RecordOptions) -> Self {
+        Self {
+            canvas: Canvas::default(),
+            options,
+            state: RwLock::new(RecorderState::default()),
+        }
+    }
+
+    pub fn get_options(&self) -> RecordOptions {
+        self.options
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.state.read().unwrap().frames.len()
+    }
+
+    pub fn get_frames(&self) -> Vec<RgbaImage> {
+        let state = self.state.read().unwrap();
+        state.frames
+            .iter()
+            .map(|(size, pixels)| pixels_to_img(*size, pixels, self.options.scale))
+            .collect()
+    }
+
+    // The format is picked by the file extension, see `save_animation`
+    pub fn save(&self, path: &str) -> Result<()> {
+        let state = self.state.read().unwrap();
+        if state.frames.is_empty() {
+            return Err(anyhow!("Nothing was recorded"));
+        }
+
+        // Scaled one frame at a time while encoding, instead of all of them up front
+        let frames = state.frames
+            .iter()
+            .map(|(size, pixels)| pixels_to_img(*size, pixels, self.options.scale));
+        save_animation(frames, &self.options, path)
+    }
+
+    fn capture(&self, state: &mut RecorderState) {
+        let size = self.canvas.get_size();
+        if size.is_empty() {
+            return;
+        }
+
+        let pixels = self.canvas.render();
+        let frame_bytes = pixels.len() * std::mem::size_of::<Color>();
+        // The new frame is always kept, so the last one of a run is never lost
+        while state.bytes + frame_bytes > self.options.max_bytes && state.frames.len() > 1 {
+            let mut index = 0;
+            state.frames.retain(|_| {
+                index += 1;
+                index % 2 == 1
+            });
+            state.stride *= 2;
+            state.bytes = state.frames.iter().map(|(_, e)| e.len() * std::mem::size_of::<Color>()).sum();
+        }
+
+        state.bytes += frame_bytes;
+        state.frames.push((size, pixels));
+    }
+}
+
+impl MazeObserver for Recorder {
+    fn on_event(&self, event: &MazeEvent) -> Result<()> {
+        self.canvas.apply(event);
+
+        let mut state = self.state.write().unwrap();
+        match event {
+            MazeEvent::Step => {
+                state.steps += 1;
+                if state.steps.is_multiple_of(self.options.frame_skip.max(1) * state.stride) {
+                    self.capture(&mut state);
+                }
+            }
+            MazeEvent::Loaded(_) | MazeEvent::Finished => self.capture(&mut state),
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
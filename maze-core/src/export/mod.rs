@@ -0,0 +1,3 @@
+pub mod pdf;
+pub mod segments;
+pub mod svg;
@@ -0,0 +1,252 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    point::point::Point,
+    run::MazeRun,
+    solve::solve::SolveOptions,
+    tools::{color::Color, render::leg_color},
+};
+
+use super::segments::{wall_blocks, wall_segments, WallStyle};
+
+// Bezier handle length for approximating a quarter circle
+const CIRCLE_K: f64 = 0.552_284_8;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PageSize {
+    #[default]
+    A4,
+    Letter,
+}
+
+impl PageSize {
+    // Width and height in PDF points (1/72 inch)
+    pub fn get_dimensions(&self) -> (f64, f64) {
+        match self {
+            PageSize::A4 => (595.28, 841.89),
+            PageSize::Letter => (612.0, 792.0),
+        }
+    }
+}
+
+impl Display for PageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PageSize::A4 => write!(f, "A4"),
+            PageSize::Letter => write!(f, "Letter"),
+        }
+    }
+}
+
+impl FromStr for PageSize {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "a4" => Ok(PageSize::A4),
+            "letter" => Ok(PageSize::Letter),
+            _ => Err(anyhow!("Unknown page size {}, use A4 or Letter", s)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PdfOptions {
+    pub page_size: PageSize,
+    // Space around the page content in points
+    pub margin: f64,
+    pub title: String,
+    pub style: WallStyle,
+    pub stroke_width: f64,
+    // Adds one answer page per maze after all puzzle pages
+    pub solutions: bool,
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        Self {
+            page_size: PageSize::A4,
+            margin: 42.0,
+            title: "Maze".to_string(),
+            style: WallStyle::Lines,
+            stroke_width: 1.5,
+            solutions: true,
+        }
+    }
+}
+
+// Puzzle pages come first so the answers can't be seen while solving, the answer pages follow in the same order
+pub fn mazes_to_pdf(runs: &[MazeRun], options: &PdfOptions) -> Result<Vec<u8>> {
+    if runs.is_empty() {
+        return Err(anyhow!("A PDF needs at least one maze"));
+    }
+
+    let mut pages = Vec::new();
+    for (i, run) in runs.iter().enumerate() {
+        pages.push(page_content(run, i, runs.len(), false, options)?);
+    }
+
+    if options.solutions {
+        for (i, run) in runs.iter().enumerate() {
+            pages.push(page_content(run, i, runs.len(), true, options)?);
+        }
+    }
+
+    Ok(write_document(&pages, options.page_size))
+}
+
+fn page_content(run: &MazeRun, index: usize, count: usize, solution: bool, options: &PdfOptions) -> Result<String> {
+    let (width, height) = options.page_size.get_dimensions();
+    let margin = options.margin;
+    let opt = &run.maze_options;
+
+    let mut title = options.title.clone();
+    if count > 1 {
+        title = format!("{} {}", title, index + 1);
+    }
+    if solution {
+        title = format!("{} - Solution", title);
+    }
+
+    let details = format!(
+        "Seed: {}   Size: {}   Decimate: {}%   Start / End: {}",
+        opt.seed, run.size, opt.decimate, opt.placement
+    );
+
+    let mut out = String::new();
+    out.push_str(&text(margin, height - margin - 20.0, 20.0, &title));
+    out.push_str(&text(margin, height - margin - 38.0, 10.0, &details));
+
+    // The maze is scaled to the largest size that fits below the header
+    let top = height - margin - 56.0;
+    let (units_x, units_y) = options.style.get_units(run.size);
+    let scale = ((width - margin * 2.0) / units_x as f64).min((top - margin) / units_y as f64);
+    let left = (width - units_x as f64 * scale) / 2.0;
+    let to_page = |x: f64, y: f64| (left + x * scale, top - y * scale);
+
+    match options.style {
+        WallStyle::Lines => {
+            out.push_str(&format!("{} w 2 J 0 j {} RG\n", options.stroke_width, rgb(&Color::BLACK)));
+            for segment in wall_segments(&run.maze)? {
+                let (x1, y1) = to_page(segment.from.x as f64, segment.from.y as f64);
+                let (x2, y2) = to_page(segment.to.x as f64, segment.to.y as f64);
+                out.push_str(&format!("{:.2} {:.2} m {:.2} {:.2} l\n", x1, y1, x2, y2));
+            }
+            out.push_str("S\n");
+        }
+        WallStyle::Blocks => {
+            out.push_str(&format!("{} rg\n", rgb(&Color::BLACK)));
+            for block in wall_blocks(&run.maze)? {
+                let (x, y) = to_page(block.x as f64, (block.y + block.height) as f64);
+                out.push_str(&format!(
+                    "{:.2} {:.2} {:.2} {:.2} re\n",
+                    x, y, block.width as f64 * scale, block.height as f64 * scale
+                ));
+            }
+            out.push_str("f\n");
+        }
+    }
+
+    let center = |p: &Point| {
+        let (x, y) = options.style.get_center(p);
+        to_page(x, y)
+    };
+
+    if solution {
+        let line_width = (scale * 0.3).max(0.5);
+        out.push_str(&format!("{:.2} w 1 J 1 j\n", line_width));
+        for (i, leg) in run.legs.iter().enumerate().filter(|(_, e)| e.len() > 1) {
+            let color = if run.legs.len() == 1 { Color::from_rgb(255, 128, 0) } else { leg_color(i) };
+            out.push_str(&format!("{} RG\n", rgb(&color)));
+
+            let (x, y) = center(&leg[0]);
+            out.push_str(&format!("{:.2} {:.2} m\n", x, y));
+            for p in &leg[1..] {
+                let (x, y) = center(p);
+                out.push_str(&format!("{:.2} {:.2} l\n", x, y));
+            }
+            out.push_str("S\n");
+        }
+    }
+
+    let SolveOptions { start, end, .. } = run.options;
+    let radius = (scale / 3.0).max(1.0);
+    for (p, color) in [(start, Color::from_rgb(255, 0, 0)), (end, Color::from_rgb(0, 160, 0))] {
+        let (x, y) = center(&p);
+        out.push_str(&format!("{} rg\n", rgb(&color)));
+        out.push_str(&circle(x, y, radius));
+    }
+
+    Ok(out)
+}
+
+fn rgb(color: &Color) -> String {
+    format!("{:.3} {:.3} {:.3}", color.r as f64 / 255.0, color.g as f64 / 255.0, color.b as f64 / 255.0)
+}
+
+fn circle(x: f64, y: f64, r: f64) -> String {
+    let k = r * CIRCLE_K;
+    format!(
+        "{:.2} {:.2} m {:.2} {:.2} {:.2} {:.2} {:.2} {:.2} c {:.2} {:.2} {:.2} {:.2} {:.2} {:.2} c \
+         {:.2} {:.2} {:.2} {:.2} {:.2} {:.2} c {:.2} {:.2} {:.2} {:.2} {:.2} {:.2} c f\n",
+        x + r, y,
+        x + r, y + k, x + k, y + r, x, y + r,
+        x - k, y + r, x - r, y + k, x - r, y,
+        x - r, y - k, x - k, y - r, x, y - r,
+        x + k, y - r, x + r, y - k, x + r, y
+    )
+}
+
+// Helvetica only covers Latin-1, everything else is replaced
+fn text(x: f64, y: f64, font_size: f64, content: &str) -> String {
+    let escaped = content
+        .chars()
+        .map(|c| match c {
+            '(' | ')' | '\\' => format!("\\{}", c),
+            ' '..='~' => c.to_string(),
+            _ => "?".to_string(),
+        })
+        .collect::<String>();
+
+    format!("BT /F1 {} Tf {:.2} {:.2} Td ({}) Tj ET\n", font_size, x, y, escaped)
+}
+
+// Objects 1 to 3 are the catalog, the page tree and the font, every page adds a page object and its content stream
+fn write_document(pages: &[String], page_size: PageSize) -> Vec<u8> {
+    let (width, height) = page_size.get_dimensions();
+    let page_id = |i: usize| 4 + i * 2;
+
+    let kids = (0..pages.len()).map(|i| format!("{} 0 R", page_id(i))).collect::<Vec<_>>();
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), pages.len()),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+    ];
+    for (i, content) in pages.iter().enumerate() {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            width, height, page_id(i) + 1
+        ));
+        objects.push(format!("<< /Length {} >>\nstream\n{}endstream", content.len(), content));
+    }
+
+    let mut out = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).as_bytes());
+    }
+
+    let xref = out.len();
+    out.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+    for offset in offsets {
+        out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    out.extend_from_slice(
+        format!("trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n", objects.len() + 1, xref).as_bytes()
+    );
+
+    out
+}
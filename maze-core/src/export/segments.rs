@@ -0,0 +1,142 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    point::{point::Point, point_state::PointState, size::Size},
+    tools::consts::Maze,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WallStyle {
+    // Cells are rooms and walls are drawn as thin lines between them
+    #[default]
+    Lines,
+    // Every wall cell of the grid is a filled square, like the PNG export
+    Blocks,
+}
+
+impl WallStyle {
+    // Width and height of a maze in the units segments and blocks are measured in
+    pub fn get_units(&self, size: Size) -> (usize, usize) {
+        match self {
+            WallStyle::Lines => size.cells(),
+            WallStyle::Blocks => (size.width, size.height),
+        }
+    }
+
+    // Where the centre of a grid point ends up, passages between two cells lie on the wall line
+    pub fn get_center(&self, p: &Point) -> (f64, f64) {
+        match self {
+            WallStyle::Lines => (p.x as f64 / 2.0, p.y as f64 / 2.0),
+            WallStyle::Blocks => (p.x as f64 + 0.5, p.y as f64 + 0.5),
+        }
+    }
+}
+
+impl Display for WallStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WallStyle::Lines => write!(f, "Lines"),
+            WallStyle::Blocks => write!(f, "Blocks"),
+        }
+    }
+}
+
+impl FromStr for WallStyle {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "lines" | "thin" => Ok(WallStyle::Lines),
+            "blocks" | "filled" => Ok(WallStyle::Blocks),
+            _ => Err(anyhow!("Unknown wall style {}", s)),
+        }
+    }
+}
+
+// Straight wall between two corners of the cell grid, (0, 0) is the top left corner of the maze
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Segment {
+    pub from: Point,
+    pub to: Point,
+}
+
+// Rectangle of wall cells in grid coordinates
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Block {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+fn check_maze(maze: &Maze) -> Result<()> {
+    let size = maze.get_size();
+    if !size.is_valid() {
+        return Err(anyhow!("Maze of size {} has to have odd sides", size));
+    }
+
+    Ok(())
+}
+
+// Walls on even rows are horizontal, walls on even columns are vertical. Collinear walls are merged into one segment.
+pub fn wall_segments(maze: &Maze) -> Result<Vec<Segment>> {
+    let size = maze.get_size();
+    check_maze(maze)?;
+
+    let is_wall = |x: usize, y: usize| maze[Point { x, y }] == PointState::Wall;
+    let mut segments = Vec::new();
+    for line in (0..size.height).step_by(2) {
+        let mut horizontal = None;
+        for i in 0..=size.width {
+            let unit = line / 2;
+            if i < size.width && is_wall(i, line) {
+                horizontal.get_or_insert(i);
+            } else if let Some(start) = horizontal.take() {
+                // Odd cells are the walls themselves and span from one corner to the next
+                let (from, to) = (start / 2, i / 2);
+                if from < to {
+                    segments.push(Segment { from: Point { x: from, y: unit }, to: Point { x: to, y: unit } });
+                }
+            }
+        }
+    }
+
+    for line in (0..size.width).step_by(2) {
+        let mut vertical = None;
+        for i in 0..=size.height {
+            let unit = line / 2;
+            if i < size.height && is_wall(line, i) {
+                vertical.get_or_insert(i);
+            } else if let Some(start) = vertical.take() {
+                let (from, to) = (start / 2, i / 2);
+                if from < to {
+                    segments.push(Segment { from: Point { x: unit, y: from }, to: Point { x: unit, y: to } });
+                }
+            }
+        }
+    }
+
+    Ok(segments)
+}
+
+// Wall cells merged into horizontal runs
+pub fn wall_blocks(maze: &Maze) -> Result<Vec<Block>> {
+    let size = maze.get_size();
+    check_maze(maze)?;
+
+    let mut blocks = Vec::new();
+    for y in 0..size.height {
+        let mut start = None;
+        for x in 0..=size.width {
+            if x < size.width && maze[Point { x, y }] == PointState::Wall {
+                start.get_or_insert(x);
+            } else if let Some(start) = start.take() {
+                blocks.push(Block { x: start, y, width: x - start, height: 1 });
+            }
+        }
+    }
+
+    Ok(blocks)
+}
@@ -0,0 +1,136 @@
+use anyhow::Result;
+
+use crate::{
+    point::point::Point,
+    tools::{color::Color, consts::Maze, render::leg_color},
+};
+
+use super::segments::{wall_blocks, wall_segments, WallStyle};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvgOptions {
+    pub style: WallStyle,
+    // Size of one unit of the wall style in px
+    pub cell_size: f64,
+    // Only used by WallStyle::Lines
+    pub stroke_width: f64,
+    pub wall_color: Color,
+    pub background: Color,
+    pub show_solution: bool,
+    pub solution_color: Color,
+    pub solution_width: f64,
+    pub show_markers: bool,
+    pub start_color: Color,
+    pub end_color: Color,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            style: WallStyle::Lines,
+            cell_size: 16.0,
+            stroke_width: 2.0,
+            wall_color: Color::BLACK,
+            background: Color::WHITE,
+            show_solution: true,
+            solution_color: Color::from_rgb(255, 128, 0),
+            solution_width: 4.0,
+            show_markers: true,
+            start_color: Color::from_rgb(255, 0, 0),
+            end_color: Color::from_rgb(0, 255, 0),
+        }
+    }
+}
+
+impl Color {
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+// Paints the legs of a solution on top of the walls, every leg gets its own colour if there are multiple
+pub fn maze_to_svg(maze: &Maze, legs: &[Vec<Point>], markers: Option<(Point, Point)>, options: &SvgOptions) -> Result<String> {
+    let size = maze.get_size();
+    let SvgOptions { style, cell_size, stroke_width, .. } = *options;
+
+    // Thin walls are centred on the border, so half of them would be cut off without a margin
+    let margin = if style == WallStyle::Lines { stroke_width } else { 0.0 };
+    let (units_x, units_y) = style.get_units(size);
+    let total_x = units_x as f64 * cell_size + margin * 2.0;
+    let total_y = units_y as f64 * cell_size + margin * 2.0;
+
+    let mut out = String::new();
+    out.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\">\n",
+        total_x, total_y, -margin, -margin, total_x, total_y
+    ));
+    out.push_str(&format!(
+        "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+        -margin, -margin, total_x, total_y, options.background.to_hex()
+    ));
+
+    match style {
+        WallStyle::Lines => {
+            let path = wall_segments(maze)?
+                .iter()
+                .map(|e| format!(
+                    "M{} {}L{} {}",
+                    e.from.x as f64 * cell_size, e.from.y as f64 * cell_size,
+                    e.to.x as f64 * cell_size, e.to.y as f64 * cell_size
+                ))
+                .collect::<String>();
+
+            out.push_str(&format!(
+                "  <path d=\"{}\" stroke=\"{}\" stroke-width=\"{}\" stroke-linecap=\"square\" fill=\"none\"/>\n",
+                path, options.wall_color.to_hex(), stroke_width
+            ));
+        }
+        WallStyle::Blocks => {
+            out.push_str(&format!("  <g fill=\"{}\" shape-rendering=\"crispEdges\">\n", options.wall_color.to_hex()));
+            for block in wall_blocks(maze)? {
+                out.push_str(&format!(
+                    "    <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>\n",
+                    block.x as f64 * cell_size, block.y as f64 * cell_size,
+                    block.width as f64 * cell_size, block.height as f64 * cell_size
+                ));
+            }
+            out.push_str("  </g>\n");
+        }
+    }
+
+    let to_px = |p: &Point| {
+        let (x, y) = style.get_center(p);
+        (x * cell_size, y * cell_size)
+    };
+
+    if options.show_solution {
+        for (i, leg) in legs.iter().enumerate().filter(|(_, e)| e.len() > 1) {
+            let color = if legs.len() == 1 { options.solution_color } else { leg_color(i) };
+            let points = leg
+                .iter()
+                .map(|p| {
+                    let (x, y) = to_px(p);
+                    format!("{},{}", x, y)
+                })
+                .collect::<Vec<_>>();
+
+            out.push_str(&format!(
+                "  <polyline points=\"{}\" stroke=\"{}\" stroke-width=\"{}\" stroke-linecap=\"round\" stroke-linejoin=\"round\" fill=\"none\"/>\n",
+                points.join(" "), color.to_hex(), options.solution_width
+            ));
+        }
+    }
+
+    if let Some((start, end)) = markers.filter(|_| options.show_markers) {
+        for (p, color) in [(start, options.start_color), (end, options.end_color)] {
+            let (x, y) = to_px(&p);
+            out.push_str(&format!(
+                "  <circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>\n",
+                x, y, cell_size / 3.0, color.to_hex()
+            ));
+        }
+    }
+
+    out.push_str("</svg>\n");
+    Ok(out)
+}
@@ -0,0 +1,53 @@
+use anyhow::Result;
+use rand::Rng;
+
+use crate::{
+    events::event::MazeEvent,
+    point::{direction::Direction, point::Point, point_state::PointState},
+    tools::{
+        consts::get_seeder,
+        options::MazeData,
+        storage::MazeStorage,
+    },
+};
+
+pub fn decimate_maze<M: MazeStorage>(data: &MazeData, maze: &mut M) -> Result<()> {
+    let size = maze.get_size();
+    let factor = data.get_opt().decimate;
+    let mut seeder = get_seeder(data);
+    data.emit(MazeEvent::Message("Decimating...".to_string()))?;
+    for y in 1..size.height - 1 {
+        for x in 1..size.width - 1 {
+            let p = Point { x, y };
+            let is_wall =
+                |d: &Direction| {
+                    let state = match *d {
+                        Direction::DOWN => maze.get_state(&p.add(0, -1).unwrap()),
+                        Direction::UP => maze.get_state(&p.add(0, 1).unwrap()),
+                        Direction::LEFT => maze.get_state(&p.add(-1,0).unwrap()),
+                        Direction::RIGHT => maze.get_state(&p.add(1, 0).unwrap())
+                    };
+
+                    state == PointState::Wall
+                };
+
+            let is_corridor = is_wall(&Direction::LEFT)
+                && is_wall(&Direction::RIGHT)
+                && !is_wall(&Direction::UP)
+                && !is_wall(&Direction::DOWN)
+                || is_wall(&Direction::UP)
+                    && is_wall(&Direction::DOWN)
+                    && !is_wall(&Direction::LEFT)
+                    && !is_wall(&Direction::RIGHT);
+
+            //Probability of making a wall a coridor
+            if is_corridor && seeder.gen_range(0..100) < factor && seeder.gen_range(0..100) < factor {
+                maze.set_state(&p, PointState::Passage);
+                data.emit(MazeEvent::CellCarved(p))?;
+            }
+        }
+    }
+
+    data.emit(MazeEvent::Step)?;
+    Ok(())
+}
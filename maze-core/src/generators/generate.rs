@@ -0,0 +1,77 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+use crate::{events::event::MazeEvent, analysis::validate::validate, tools::{consts::{get_size, Maze}, options::MazeData, storage::MazeStorage}, point::point_state::PointState};
+
+use super::{hunt_and_kill, hunt_and_kill_scan, parallel};
+
+// Algorithm a maze was created with, stored by the file formats that keep metadata
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Generator {
+    HuntAndKill,
+    // Hunt and kill that scans for the next cell instead of queueing every carved one, needs less memory for huge mazes
+    HuntAndKillScan,
+    // Hunt and kill on separate regions at the same time, which are connected afterwards
+    Parallel,
+}
+
+impl Generator {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Generator::HuntAndKill => "hunt_and_kill",
+            Generator::HuntAndKillScan => "hunt_and_kill_scan",
+            Generator::Parallel => "parallel",
+        }
+    }
+
+    pub fn all() -> Vec<Generator> {
+        vec![Generator::HuntAndKill, Generator::HuntAndKillScan, Generator::Parallel]
+    }
+}
+
+impl Display for Generator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+impl FromStr for Generator {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let normalized = s.to_lowercase().replace(['-', ' '], "_");
+        Generator::all()
+            .into_iter()
+            .find(|e| e.get_name() == normalized)
+            .ok_or_else(|| anyhow!("Unknown generator {}", s))
+    }
+}
+
+pub fn generate(data: &MazeData) -> anyhow::Result<Maze> {
+    generate_as(data)
+}
+
+// Generates into any storage, the same seed carves the same maze no matter which one is used
+pub fn generate_as<M: MazeStorage>(data: &MazeData) -> anyhow::Result<M> {
+    let size = get_size(data)?;
+
+    let mut maze = M::filled(size, PointState::Wall);
+    data.emit(MazeEvent::Started { size })?;
+    match data.get_opt().generator.unwrap_or(Generator::HuntAndKill) {
+        Generator::HuntAndKill => hunt_and_kill(&mut maze, data)?,
+        Generator::HuntAndKillScan => hunt_and_kill_scan(&mut maze, data)?,
+        Generator::Parallel => parallel(&mut maze, data)?,
+    }
+
+    if data.get_opt().validate {
+        let validation = validate(&maze)?;
+        if !validation.perfect || !validation.border_breaks.is_empty() {
+            return Err(anyhow!("Generated an invalid maze. {}", validation));
+        }
+    }
+
+    Ok(maze)
+}
\ No newline at end of file
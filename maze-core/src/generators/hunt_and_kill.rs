@@ -0,0 +1,266 @@
+use std::collections::VecDeque;
+
+use crate::{
+    events::event::{overlay_events, MazeEvent},
+    point::{
+        direction::Direction,
+        point::Point,
+        point_state::{PointState, VisualIndicator},
+        sparse_grid::SparseGrid,
+    },
+    tools::{
+        consts::{get_seeder, set_seeder, VisualOverwrites},
+        matrix::{count_dirs_state, get_surrounding_walls, go_to_dir},
+        options::MazeData,
+        storage::MazeStorage,
+    }
+};
+use anyhow::{anyhow, Result};
+use rand::{rngs::StdRng, Rng};
+
+use super::tools::{count_to_percentage, rand_el};
+
+pub fn hunt_and_kill<M: MazeStorage>(maze: &mut M, data: &MazeData) -> anyhow::Result<()> {
+    let (cells_x, cells_y) = maze.get_size().cells();
+    let mut seeder = get_seeder(data);
+
+    // making sure that passage are always on odd points
+    let x = seeder.gen_range(0..cells_x) * 2 + 1;
+    let y = seeder.gen_range(0..cells_y) * 2 + 1;
+
+    let start_p = Point { x, y };
+    let mut hunter = QueueHunter { to_hunt: VecDeque::from([start_p]) };
+    walk(maze, data, &mut seeder, start_p, &mut hunter)?;
+
+    set_seeder(data, seeder);
+    Ok(())
+}
+
+// Same walk as hunt_and_kill, but the hunt scans the cells row by row instead of keeping every carved cell
+// in a queue, so nothing but the maze itself grows with the size. Carves other mazes than hunt_and_kill.
+pub fn hunt_and_kill_scan<M: MazeStorage>(maze: &mut M, data: &MazeData) -> anyhow::Result<()> {
+    let mut seeder = get_seeder(data);
+
+    // Starting in the first cell keeps the cell under the cursor carved, see ScanHunter
+    let start_p = Point { x: 1, y: 1 };
+    let mut hunter = ScanHunter { cursor: 0 };
+    walk(maze, data, &mut seeder, start_p, &mut hunter)?;
+
+    set_seeder(data, seeder);
+    Ok(())
+}
+
+// Picks the cell the walk goes on from once it got stuck
+trait Hunter<M: MazeStorage> {
+    fn carved(&mut self, p: Point);
+    // The cell together with its walls, None once every cell is carved
+    fn hunt(&mut self, maze: &mut M, data: &MazeData, show_anim: bool) -> Result<Option<(Point, Vec<Direction>)>>;
+}
+
+fn walk<M: MazeStorage, H: Hunter<M>>(maze: &mut M, data: &MazeData, seeder: &mut StdRng, start_p: Point, hunter: &mut H) -> Result<()> {
+    let size = maze.get_size();
+    let mut last_percentage = 0.0;
+    let mut count = 0_u64;
+
+    let mut show_anim = data.show_anim() && data.has_observers();
+
+    // The walk only ever goes on from the cell it carved last
+    let mut current = Some(start_p);
+
+    let mut update_rng = rand::thread_rng();
+    while let Some(mut p) = current.take() {
+        if update_rng.gen_bool(0.3) {
+            show_anim = data.show_anim() && data.has_observers();
+        }
+
+        let mut dirs = get_surrounding_walls(maze, &p)?;
+
+        if dirs.is_empty() {
+            match hunter.hunt(maze, data, show_anim)? {
+                Some((found, found_dirs)) => {
+                    p = found;
+                    dirs = found_dirs;
+                }
+                None => break,
+            }
+        }
+
+        let rand_dir = rand_el(seeder, &dirs);
+        let neighbor = go_to_dir(&size, &p, &rand_dir);
+        count += 1;
+        if let Some(e) = count_to_percentage(data, size, count, &mut last_percentage) {
+            last_percentage = e;
+            data.emit(MazeEvent::Progress(e))?;
+        }
+
+        if neighbor.is_none() {
+            continue;
+        }
+
+        let neighbor = neighbor.unwrap();
+
+        remove_wall(maze, &p, &neighbor)?;
+        current = Some(neighbor);
+        hunter.carved(neighbor);
+
+        if data.has_observers() {
+            let between = Point { x: (p.x + neighbor.x) / 2, y: (p.y + neighbor.y) / 2 };
+            data.emit_all(vec![
+                MazeEvent::CellCarved(p),
+                MazeEvent::CellCarved(between),
+                MazeEvent::CellCarved(neighbor),
+                MazeEvent::Step,
+            ])?;
+        }
+    }
+
+    data.emit(MazeEvent::Progress(1.0))?;
+    Ok(())
+}
+
+// Hunts through every carved cell in the order it was carved
+struct QueueHunter {
+    to_hunt: VecDeque<Point>,
+}
+
+impl<M: MazeStorage> Hunter<M> for QueueHunter {
+    fn carved(&mut self, p: Point) {
+        self.to_hunt.push_back(p);
+    }
+
+    fn hunt(&mut self, maze: &mut M, data: &MazeData, show_anim: bool) -> Result<Option<(Point, Vec<Direction>)>> {
+        let size = maze.get_size();
+        let mut visual_overwrites = SparseGrid::new(size);
+        let mut found = None;
+
+        let all_dirs = Direction::all().len();
+        // Cells only ever lose walls, so a cell without any walls left is dropped for good instead of being
+        // queued again. Every carved cell is looked at once at most, which keeps the hunt linear overall.
+        while let Some(p) = self.to_hunt.pop_front() {
+            if show_anim {
+                visual_overwrites.insert(p, VisualIndicator::Searching);
+            }
+
+            if count_dirs_state(maze, &p, PointState::Wall) == 0 {
+                let passages = count_dirs_state(maze, &p, PointState::Passage);
+                if passages == 0 {
+                    return Err(anyhow!("No passages and somehow no walls ?!?!?"))
+                }
+
+                // Cells on the border are never fully surrounded by passages
+                if passages != all_dirs {
+                    continue;
+                }
+                if show_anim {
+                    visual_overwrites.insert(p, VisualIndicator::SolvePath);
+                }
+
+
+                maze.set_state(&p, PointState::Passage);
+                data.emit(MazeEvent::CellCarved(p))?;
+                continue;
+            }
+
+            found = Some((p, get_surrounding_walls(maze, &p)?));
+            break;
+        }
+
+        if show_anim {
+            if let Some((p, _)) = found {
+                visual_overwrites.insert(p, VisualIndicator::Match);
+            }
+            show_hunt(data, &visual_overwrites)?;
+        }
+
+        Ok(found)
+    }
+}
+
+// Moves a cursor over the cells in row major order. Every cell in front of the cursor is carved and has no
+// walls left, which never changes again, so the cursor never has to go back. The cell under the cursor is
+// always carved as well: its upper and left neighbour are done, so they can't be next to an uncarved cell.
+struct ScanHunter {
+    cursor: usize,
+}
+
+impl<M: MazeStorage> Hunter<M> for ScanHunter {
+    fn carved(&mut self, _p: Point) {}
+
+    fn hunt(&mut self, maze: &mut M, data: &MazeData, show_anim: bool) -> Result<Option<(Point, Vec<Direction>)>> {
+        let size = maze.get_size();
+        let (cells_x, cells_y) = size.cells();
+        while self.cursor < cells_x * cells_y {
+            let p = Point { x: self.cursor % cells_x * 2 + 1, y: self.cursor / cells_x * 2 + 1 };
+            let dirs = get_surrounding_walls(maze, &p)?;
+            if maze.is_passage(&p) && !dirs.is_empty() {
+                if show_anim {
+                    let mut visual_overwrites = SparseGrid::new(size);
+                    visual_overwrites.insert(p, VisualIndicator::Match);
+                    show_hunt(data, &visual_overwrites)?;
+                }
+
+                return Ok(Some((p, dirs)));
+            }
+
+            self.cursor += 1;
+        }
+
+        Ok(None)
+    }
+}
+
+fn show_hunt(data: &MazeData, visual_overwrites: &VisualOverwrites) -> Result<()> {
+    data.emit_all(overlay_events(visual_overwrites))?;
+    for _ in 0..5 {
+        data.emit(MazeEvent::Step)?;
+    }
+    data.emit(MazeEvent::OverlayCleared)?;
+
+    Ok(())
+}
+
+pub fn remove_wall<M: MazeStorage>(maze: &mut M, from: &Point, to: &Point) -> Result<()> {
+    if from.x != to.x && from.y != to.y {
+        return Err(anyhow!(
+            "Points have to be either the same on x or y axis to draw a wall"
+        ));
+    }
+
+    let Point {
+        x: from_x,
+        y: from_y,
+    } = from;
+    let Point { x: to_x, y: to_y } = to;
+
+    let diff_x = (*to_x as i32) - (*from_x as i32);
+    let diff_y = (*to_y as i32) - (*from_y as i32);
+
+    let step_x = if diff_x != 0 {
+        diff_x / diff_x.abs()
+    } else {
+        0
+    };
+    let step_y = if diff_y != 0 {
+        diff_y / diff_y.abs()
+    } else {
+        0
+    };
+
+    for curr_x in 0..diff_x.abs() + 1 {
+        let correct_x = *from_x as i32 + curr_x * step_x;
+
+        for curr_y in 0..diff_y.abs() + 1 {
+            let correct_y = *from_y as i32 + curr_y * step_y;
+            if correct_x < 0 || correct_y < 0 {
+                continue;
+            }
+
+            let p = Point { x: correct_x as usize, y: correct_y as usize };
+            if maze.contains(&p) {
+                maze.set_state(&p, PointState::Passage);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod generate;
+mod hunt_and_kill;
+pub use hunt_and_kill::{hunt_and_kill, hunt_and_kill_scan};
+mod parallel;
+pub use parallel::parallel;
+pub mod tools;
+pub mod decimate;
\ No newline at end of file
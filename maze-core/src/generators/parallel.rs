@@ -0,0 +1,203 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use anyhow::{anyhow, Result};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{
+    events::event::MazeEvent,
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    tools::{
+        consts::{Maze, MazeOptions},
+        math::mix_seed,
+        options::{AnimOptions, MazeData},
+        storage::MazeStorage,
+    },
+};
+
+use super::hunt_and_kill;
+
+// Regions every thread gets on average, so threads that finish early can take over the remaining ones
+const REGIONS_PER_THREAD: usize = 4;
+
+// Rectangle of cells, neighbouring regions share the wall between them
+#[derive(Clone, Copy, Debug)]
+struct Region {
+    column: usize,
+    row: usize,
+    // First cell and the cell after the last one on both axes
+    cells_x: (usize, usize),
+    cells_y: (usize, usize),
+}
+
+impl Region {
+    fn get_size(&self) -> Size {
+        Size::new((self.cells_x.1 - self.cells_x.0) * 2 + 1, (self.cells_y.1 - self.cells_y.0) * 2 + 1)
+    }
+
+    // Point of the maze the top left corner of the region is at
+    fn get_origin(&self) -> Point {
+        Point { x: self.cells_x.0 * 2, y: self.cells_y.0 * 2 }
+    }
+}
+
+// Splits the maze into regions that are generated with hunt and kill at the same time. The regions are
+// connected along a random spanning tree with a single door per edge, so the result is still perfect.
+// Every region has its own seed, only the layout of the regions depends on the amount of threads.
+pub fn parallel<M: MazeStorage>(maze: &mut M, data: &MazeData) -> Result<()> {
+    let opt = data.get_opt();
+    let threads = opt.threads.max(1);
+    let (columns, rows) = get_layout(maze.get_size(), threads * REGIONS_PER_THREAD);
+    let regions = get_regions(maze.get_size(), columns, rows);
+
+    let next = AtomicUsize::new(0);
+    let finished = AtomicUsize::new(0);
+    let generated = thread::scope(|s| {
+        let workers: Vec<_> = (0..threads.min(regions.len()))
+            .map(|_| s.spawn(|| -> Result<Vec<(usize, Maze)>> {
+                let mut done = Vec::new();
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= regions.len() {
+                        break;
+                    }
+
+                    if data.should_exit() {
+                        return Err(anyhow!("Terminated."));
+                    }
+
+                    done.push((index, generate_region(&regions[index], opt.seed, index)?));
+                    let count = finished.fetch_add(1, Ordering::Relaxed) + 1;
+                    data.emit(MazeEvent::Progress(count as f64 / regions.len() as f64))?;
+                }
+
+                Ok(done)
+            }))
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|e| e.join().map_err(|_| anyhow!("A generator thread panicked"))?)
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    let mut generated: Vec<(usize, Maze)> = generated.into_iter().flatten().collect();
+    generated.sort_by_key(|(index, _)| *index);
+
+    // Copied in order of the regions, so observers always see the same events
+    for (index, region_maze) in &generated {
+        let origin = regions[*index].get_origin();
+        for (p, state) in region_maze.enumerate() {
+            if *state != PointState::Passage {
+                continue;
+            }
+
+            let p = Point { x: origin.x + p.x, y: origin.y + p.y };
+            maze.set_state(&p, PointState::Passage);
+            if data.has_observers() {
+                data.emit(MazeEvent::CellCarved(p))?;
+            }
+        }
+
+        data.emit(MazeEvent::Step)?;
+    }
+
+    for door in get_doors(&regions, columns, rows, opt.seed) {
+        maze.set_state(&door, PointState::Passage);
+        data.emit(MazeEvent::CellCarved(door))?;
+    }
+
+    data.emit(MazeEvent::Progress(1.0))?;
+    Ok(())
+}
+
+fn generate_region(region: &Region, seed: u64, index: usize) -> Result<Maze> {
+    let size = region.get_size();
+    let opt = MazeOptions::rect(size.width, size.height, mix_seed(seed, &[index as u64]), 0);
+    let data = MazeData::headless(&opt, &AnimOptions::new(false, false, 1.0));
+
+    let mut maze = Grid::new(size, PointState::Wall);
+    hunt_and_kill(&mut maze, &data)?;
+    Ok(maze)
+}
+
+// Columns and rows of regions, the regions are kept about as square as the maze allows. Every region is at
+// least two cells wide and tall, hunt and kill fails on regions that are a single cell across.
+fn get_layout(size: Size, target: usize) -> (usize, usize) {
+    let (cells_x, cells_y) = size.cells();
+    let (max_columns, max_rows) = ((cells_x / 2).max(1), (cells_y / 2).max(1));
+    let rows = ((target as f64 * cells_y as f64 / cells_x.max(1) as f64).sqrt().round() as usize).clamp(1, max_rows);
+    let columns = target.div_ceil(rows).clamp(1, max_columns);
+
+    (columns, rows)
+}
+
+fn get_regions(size: Size, columns: usize, rows: usize) -> Vec<Region> {
+    let (cells_x, cells_y) = size.cells();
+    let bound = |cells: usize, parts: usize, i: usize| cells * i / parts;
+
+    let mut regions = Vec::with_capacity(columns * rows);
+    for row in 0..rows {
+        for column in 0..columns {
+            regions.push(Region {
+                column,
+                row,
+                cells_x: (bound(cells_x, columns, column), bound(cells_x, columns, column + 1)),
+                cells_y: (bound(cells_y, rows, row), bound(cells_y, rows, row + 1)),
+            });
+        }
+    }
+
+    regions
+}
+
+// Random spanning tree over the regions (Kruskal), returns the point that is opened up for every edge of it
+fn get_doors(regions: &[Region], columns: usize, rows: usize, seed: u64) -> Vec<Point> {
+    let mut seeder = StdRng::seed_from_u64(mix_seed(seed, &[regions.len() as u64]));
+
+    let mut edges = Vec::new();
+    for region in regions {
+        let index = region.row * columns + region.column;
+        if region.column + 1 < columns {
+            edges.push((index, index + 1));
+        }
+        if region.row + 1 < rows {
+            edges.push((index, index + columns));
+        }
+    }
+    edges.shuffle(&mut seeder);
+
+    let mut parents: Vec<usize> = (0..regions.len()).collect();
+    let mut doors = Vec::with_capacity(regions.len().saturating_sub(1));
+    for (a, b) in edges {
+        let root_a = find_root(&mut parents, a);
+        let root_b = find_root(&mut parents, b);
+        if root_a == root_b {
+            continue;
+        }
+        parents[root_a] = root_b;
+
+        let (from, to) = (&regions[a], &regions[b]);
+        let door = if from.row == to.row {
+            let y = seeder.gen_range(from.cells_y.0..from.cells_y.1) * 2 + 1;
+            Point { x: to.cells_x.0 * 2, y }
+        } else {
+            let x = seeder.gen_range(from.cells_x.0..from.cells_x.1) * 2 + 1;
+            Point { x, y: to.cells_y.0 * 2 }
+        };
+        doors.push(door);
+    }
+
+    doors
+}
+
+fn find_root(parents: &mut [usize], mut index: usize) -> usize {
+    while parents[index] != index {
+        parents[index] = parents[parents[index]];
+        index = parents[index];
+    }
+
+    index
+}
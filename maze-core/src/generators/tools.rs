@@ -0,0 +1,28 @@
+use rand::{rngs::StdRng, Rng};
+
+use crate::{point::size::Size, tools::options::MazeData};
+
+pub fn count_to_percentage(data: &MazeData, size: Size, count: u64, last_percentage: &mut f64) -> Option<f64> {
+    if !count.is_multiple_of(data.speed_anim().max(1.0) as u64) {
+        return None;
+    }
+
+    // Every cell except the first one is carved once
+    let (cells_x, cells_y) = size.cells();
+    let total = (cells_x * cells_y).saturating_sub(1).max(1);
+    let rounded = (count as f64 / total as f64 * 100.0 * 10.0).round() / (10.0 * 100.0);
+    if rounded - *last_percentage < 0.003 {
+        return None;
+    }
+
+
+    Some(rounded)
+}
+
+pub fn rand_el<T>(seeder: &mut StdRng, arr: &[T]) -> T
+    where
+    T: Copy
+{
+    let index = seeder.gen_range(0..arr.len());
+    arr[index]
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use super::graph::{MazeGraph, NodeKind};
+
+impl NodeKind {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            NodeKind::DeadEnd => "dead_end",
+            NodeKind::Junction => "junction",
+            NodeKind::Pinned => "pinned",
+            NodeKind::Isolated => "isolated",
+            NodeKind::Loop => "loop",
+        }
+    }
+}
+
+impl MazeGraph {
+    // Graphviz DOT, node positions are the maze coordinates so `neato -n` keeps the layout of the maze
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("graph maze {\n");
+        for (i, p) in self.get_nodes().iter().enumerate() {
+            out.push_str(&format!(
+                "    n{} [label=\"{},{}\", kind=\"{}\", pos=\"{},{}!\"];\n",
+                i, p.x, p.y, self.get_kind(i).get_name(), p.x, p.y
+            ));
+        }
+
+        for corridor in self.get_corridors() {
+            let weight = corridor.get_weight();
+            out.push_str(&format!(
+                "    n{} -- n{} [weight={}, label=\"{}\"];\n",
+                corridor.from, corridor.to, weight, weight
+            ));
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        out.push_str("  <key id=\"x\" for=\"node\" attr.name=\"x\" attr.type=\"int\"/>\n");
+        out.push_str("  <key id=\"y\" for=\"node\" attr.name=\"y\" attr.type=\"int\"/>\n");
+        out.push_str("  <key id=\"kind\" for=\"node\" attr.name=\"kind\" attr.type=\"string\"/>\n");
+        out.push_str("  <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"long\"/>\n");
+        out.push_str("  <graph id=\"maze\" edgedefault=\"undirected\">\n");
+
+        for (i, p) in self.get_nodes().iter().enumerate() {
+            out.push_str(&format!(
+                "    <node id=\"n{}\"><data key=\"x\">{}</data><data key=\"y\">{}</data><data key=\"kind\">{}</data></node>\n",
+                i, p.x, p.y, self.get_kind(i).get_name()
+            ));
+        }
+
+        for (i, corridor) in self.get_corridors().iter().enumerate() {
+            out.push_str(&format!(
+                "    <edge id=\"e{}\" source=\"n{}\" target=\"n{}\"><data key=\"weight\">{}</data></edge>\n",
+                i, corridor.from, corridor.to, corridor.get_weight()
+            ));
+        }
+
+        out.push_str("  </graph>\n");
+        out.push_str("</graphml>\n");
+        out
+    }
+}
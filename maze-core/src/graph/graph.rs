@@ -0,0 +1,259 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    point::{grid::Grid, point::Point},
+    tools::{
+        math::get_dist,
+        matrix::get_connected_cells,
+        storage::MazeStorage,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    DeadEnd,
+    Junction,
+    // Cells with two openings that were kept as node because they were requested, e.g. start and end
+    Pinned,
+    // Cells without any opening
+    Isolated,
+    // Cell that stands for a loop without any junction or dead end, otherwise the loop would have no node
+    Loop,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Corridor {
+    pub from: usize,
+    pub to: usize,
+    // Every cell from the `from` node to the `to` node, both included
+    pub cells: Vec<Point>,
+}
+
+impl Corridor {
+    // Amount of cell steps needed to walk through the corridor
+    pub fn get_weight(&self) -> u64 {
+        (self.cells.len() - 1) as u64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphEdge {
+    pub to: usize,
+    pub weight: u64,
+    pub corridor: usize,
+}
+
+// Junctions and dead ends of a maze connected by weighted edges, every chain of cells
+// with exactly two openings in between them is collapsed into a single corridor
+pub struct MazeGraph {
+    nodes: Vec<Point>,
+    kinds: Vec<NodeKind>,
+    index: HashMap<Point, usize>,
+    adjacency: Vec<Vec<GraphEdge>>,
+    corridors: Vec<Corridor>,
+}
+
+impl MazeGraph {
+    // `pinned` cells always become nodes, so paths can start and end on them
+    pub fn from_maze<M: MazeStorage>(maze: &M, pinned: &[Point]) -> Result<Self> {
+        let size = maze.get_size();
+        let mut graph = Self {
+            nodes: Vec::new(),
+            kinds: Vec::new(),
+            index: HashMap::new(),
+            adjacency: Vec::new(),
+            corridors: Vec::new(),
+        };
+
+        let mut degrees = Grid::new(size, None);
+        for y in (1..size.height).step_by(2) {
+            for x in (1..size.width).step_by(2) {
+                let p = Point { x, y };
+                if !maze.is_passage(&p) {
+                    continue;
+                }
+
+                let degree = get_connected_cells(maze, &p)?.len();
+                degrees[&p] = Some(degree);
+
+                let kind = match degree {
+                    0 => NodeKind::Isolated,
+                    1 => NodeKind::DeadEnd,
+                    2 if pinned.contains(&p) => NodeKind::Pinned,
+                    2 => continue,
+                    _ => NodeKind::Junction,
+                };
+
+                graph.add_node(p, kind);
+            }
+        }
+
+        for p in pinned {
+            if degrees[p].is_none() {
+                return Err(anyhow!("{} is not a passage cell and can not be part of the graph", p));
+            }
+        }
+
+        // Inner cells of corridors that were already walked from their other end
+        let mut walked = Grid::new(size, false);
+        for from in 0..graph.nodes.len() {
+            graph.walk_corridors(maze, from, &mut walked)?;
+        }
+
+        // Cells of loops that only consist of corridors are never reached from a node, one of them becomes one
+        for y in (1..size.height).step_by(2) {
+            for x in (1..size.width).step_by(2) {
+                let p = Point { x, y };
+                if degrees[&p] != Some(2) || walked[&p] || graph.index.contains_key(&p) {
+                    continue;
+                }
+
+                let from = graph.add_node(p, NodeKind::Loop);
+                graph.walk_corridors(maze, from, &mut walked)?;
+            }
+        }
+
+        Ok(graph)
+    }
+
+    fn add_node(&mut self, p: Point, kind: NodeKind) -> usize {
+        let index = self.nodes.len();
+        self.index.insert(p, index);
+        self.nodes.push(p);
+        self.kinds.push(kind);
+        self.adjacency.push(Vec::new());
+
+        index
+    }
+
+    // Follows every opening of the node until the next node and adds the corridors that weren't added yet
+    fn walk_corridors<M: MazeStorage>(&mut self, maze: &M, from: usize, walked: &mut Grid<bool>) -> Result<()> {
+        let start = self.nodes[from];
+        for first in get_connected_cells(maze, &start)? {
+            let mut cells = vec![start, first];
+            let mut prev = start;
+            let mut curr = first;
+            while !self.index.contains_key(&curr) {
+                let next = get_connected_cells(maze, &curr)?
+                    .into_iter()
+                    .find(|e| *e != prev)
+                    .ok_or_else(|| anyhow!("Corridor at {} has no way out", curr))?;
+
+                cells.push(next);
+                prev = curr;
+                curr = next;
+            }
+
+            let to = self.index[&curr];
+            let is_direct = cells.len() == 2;
+            if (is_direct && to < from) || (!is_direct && walked[&first]) {
+                continue;
+            }
+
+            for cell in &cells[1..cells.len() - 1] {
+                walked[cell] = true;
+            }
+
+            self.add_corridor(Corridor { from, to, cells });
+        }
+
+        Ok(())
+    }
+
+    fn add_corridor(&mut self, corridor: Corridor) {
+        let index = self.corridors.len();
+        let weight = corridor.get_weight();
+
+        self.adjacency[corridor.from].push(GraphEdge { to: corridor.to, weight, corridor: index });
+        if corridor.from != corridor.to {
+            self.adjacency[corridor.to].push(GraphEdge { to: corridor.from, weight, corridor: index });
+        }
+
+        self.corridors.push(corridor);
+    }
+
+    pub fn get_nodes(&self) -> &Vec<Point> {
+        &self.nodes
+    }
+
+    pub fn get_kind(&self, node: usize) -> NodeKind {
+        self.kinds[node]
+    }
+
+    pub fn get_edges(&self, node: usize) -> &Vec<GraphEdge> {
+        &self.adjacency[node]
+    }
+
+    pub fn get_corridors(&self) -> &Vec<Corridor> {
+        &self.corridors
+    }
+
+    pub fn node_index(&self, p: &Point) -> Option<usize> {
+        self.index.get(p).copied()
+    }
+
+    // Cells of the corridor behind the edge in the order they are walked when leaving `from`
+    pub fn corridor_path(&self, from: usize, edge: &GraphEdge) -> Vec<Point> {
+        let corridor = &self.corridors[edge.corridor];
+        let mut cells = corridor.cells.clone();
+        if corridor.from != from {
+            cells.reverse();
+        }
+
+        cells
+    }
+
+    // A* over the nodes, returns the amount of cell steps and every cell of the path.
+    // Start and end have to be nodes, so they should be pinned when building the graph.
+    pub fn shortest_path(&self, start: &Point, end: &Point) -> Result<Option<(u64, Vec<Point>)>> {
+        let start_index = self.node_index(start).ok_or_else(|| anyhow!("{} is not a node of the graph", start))?;
+        let end_index = self.node_index(end).ok_or_else(|| anyhow!("{} is not a node of the graph", end))?;
+
+        // Corridors can't be shorter than the manhattan distance in cell steps
+        let h = |node: usize| get_dist(&self.nodes[node], end) / 2;
+
+        let mut costs = vec![u64::MAX; self.nodes.len()];
+        let mut parents: Vec<Option<(usize, GraphEdge)>> = vec![None; self.nodes.len()];
+        let mut pending = BinaryHeap::new();
+
+        costs[start_index] = 0;
+        pending.push(Reverse((h(start_index), start_index)));
+        while let Some(Reverse((_, node))) = pending.pop() {
+            if node == end_index {
+                break;
+            }
+
+            for edge in self.get_edges(node) {
+                let cost = costs[node] + edge.weight;
+                if cost < costs[edge.to] {
+                    costs[edge.to] = cost;
+                    parents[edge.to] = Some((node, *edge));
+                    pending.push(Reverse((cost + h(edge.to), edge.to)));
+                }
+            }
+        }
+
+        if costs[end_index] == u64::MAX {
+            return Ok(None);
+        }
+
+        let mut steps = Vec::new();
+        let mut curr = end_index;
+        while let Some((parent, edge)) = parents[curr] {
+            steps.push((parent, edge));
+            curr = parent;
+        }
+
+        let mut path = vec![*start];
+        for (from, edge) in steps.iter().rev() {
+            path.extend(self.corridor_path(*from, edge).into_iter().skip(1));
+        }
+
+        Ok(Some((costs[end_index], path)))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod export;
+#[allow(clippy::module_inception)]
+pub mod graph;
\ No newline at end of file
@@ -0,0 +1,14 @@
+// Generators, solvers, analysis and file formats for mazes without any GUI dependency
+
+pub mod analysis;
+pub mod chunks;
+pub mod events;
+pub mod export;
+pub mod generators;
+pub mod graph;
+pub mod point;
+pub mod run;
+pub mod solve;
+#[cfg(test)]
+mod tests;
+pub mod tools;
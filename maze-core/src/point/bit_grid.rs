@@ -0,0 +1,97 @@
+use anyhow::{anyhow, Result};
+
+use super::{point::Point, size::Size};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+// Row major grid of booleans that only needs a single bit per point,
+// so a 100000x100000 grid takes about 1.2 GB instead of 10 GB
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct BitGrid {
+    size: Size,
+    words: Vec<u64>
+}
+
+impl BitGrid {
+    pub fn new(size: Size, value: bool) -> Self {
+        let word = if value { u64::MAX } else { 0 };
+        let mut grid = Self { size, words: vec![word; size.len().div_ceil(WORD_BITS)] };
+        grid.clear_padding();
+        grid
+    }
+
+    pub fn get_size(&self) -> Size {
+        self.size
+    }
+
+    pub fn len(&self) -> usize {
+        self.size.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size.is_empty()
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.size.contains(p)
+    }
+
+    // Bytes used by the bits themselves
+    pub fn byte_len(&self) -> usize {
+        self.words.len() * std::mem::size_of::<u64>()
+    }
+
+    // Panics in debug builds if the point is outside of the grid
+    pub fn get(&self, p: &Point) -> bool {
+        debug_assert!(self.contains(p), "Point {} is outside of grid {}", p, self.size);
+        let index = p.y * self.size.width + p.x;
+        self.words[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+    }
+
+    pub fn set(&mut self, p: &Point, value: bool) {
+        debug_assert!(self.contains(p), "Point {} is outside of grid {}", p, self.size);
+        let index = p.y * self.size.width + p.x;
+        let word = &mut self.words[index / WORD_BITS];
+        if value {
+            *word |= 1 << (index % WORD_BITS);
+        } else {
+            *word &= !(1 << (index % WORD_BITS));
+        }
+    }
+
+    pub fn try_get(&self, p: &Point) -> Option<bool> {
+        if !self.contains(p) {
+            return None;
+        }
+
+        Some(self.get(p))
+    }
+
+    pub fn try_set(&mut self, p: &Point, value: bool) -> Result<()> {
+        if !self.contains(p) {
+            return Err(anyhow!("Point {} is outside of the grid", p));
+        }
+
+        self.set(p, value);
+        Ok(())
+    }
+
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(if value { u64::MAX } else { 0 });
+        self.clear_padding();
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|e| e.count_ones() as usize).sum()
+    }
+
+    // Bits after the last point are kept at zero so counting and comparing stays correct
+    fn clear_padding(&mut self) {
+        let used = self.size.len() % WORD_BITS;
+        if used != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << used) - 1;
+            }
+        }
+    }
+}
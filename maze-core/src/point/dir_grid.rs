@@ -0,0 +1,60 @@
+use super::{bit_grid::BitGrid, direction::Direction, point::Point, size::Size};
+
+// Same order as Direction::all(), without allocating on every lookup
+const DIRECTIONS: [Direction; 4] = [Direction::UP, Direction::LEFT, Direction::RIGHT, Direction::DOWN];
+
+// Optional direction for every cell of a maze in three bits, e.g. the way back to the parent of a search.
+// Cells are two points apart, so a point is looked up by half its coordinates and only every other
+// row and column takes up space. Points of different parity share their slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirGrid {
+    // Set if the cell has a direction, the direction itself is the index into DIRECTIONS.
+    // Cells without a direction that are still set only have the first of the two bits set.
+    has_dir: BitGrid,
+    bits: [BitGrid; 2],
+}
+
+impl DirGrid {
+    // `size` is the size of the maze, not the one of the grid
+    pub fn new(size: Size) -> Self {
+        let halves = Size::new(size.width.div_ceil(2), size.height.div_ceil(2));
+        Self { has_dir: BitGrid::new(halves, false), bits: [BitGrid::new(halves, false), BitGrid::new(halves, false)] }
+    }
+
+    // Bytes used by all three bit grids
+    pub fn byte_len(&self) -> usize {
+        self.has_dir.byte_len() + self.bits.iter().map(|e| e.byte_len()).sum::<usize>()
+    }
+
+    pub fn is_set(&self, p: &Point) -> bool {
+        let p = half(p);
+        self.has_dir.get(&p) || self.bits[0].get(&p)
+    }
+
+    pub fn get(&self, p: &Point) -> Option<Direction> {
+        let p = half(p);
+        if !self.has_dir.get(&p) {
+            return None;
+        }
+
+        let index = self.bits[0].get(&p) as usize | (self.bits[1].get(&p) as usize) << 1;
+        Some(DIRECTIONS[index])
+    }
+
+    // Marks the cell as set, None keeps it without a direction, e.g. for the start of a search
+    pub fn set(&mut self, p: &Point, dir: Option<Direction>) {
+        let p = half(p);
+        let index = match dir {
+            Some(dir) => DIRECTIONS.iter().position(|e| *e == dir).unwrap(),
+            None => 1,
+        };
+
+        self.has_dir.set(&p, dir.is_some());
+        self.bits[0].set(&p, index & 1 != 0);
+        self.bits[1].set(&p, index & 2 != 0);
+    }
+}
+
+fn half(p: &Point) -> Point {
+    Point { x: p.x / 2, y: p.y / 2 }
+}
@@ -3,6 +3,7 @@ use lazy_static::lazy_static;
 use crate::tools::direction_data::DirectionData;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Direction {
     UP,
     DOWN,
@@ -20,10 +21,11 @@ impl Direction {
         ]
     }
 
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_data(&self) -> DirectionData {
         for data in DIRECTION_VEC.iter() {
             if &data.dir == self {
-                return data.clone();
+                return *data;
             }
         }
 
@@ -0,0 +1,198 @@
+use std::ops::{Index, IndexMut};
+
+use anyhow::{anyhow, Result};
+
+use super::{direction::Direction, point::Point, size::Size};
+
+// Row major matrix that knows its own dimensions, so points never have to be turned into indices by hand
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    size: Size,
+    cells: Vec<T>
+}
+
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Self { size: Size::default(), cells: Vec::new() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(size: Size, value: T) -> Self {
+        Self { size, cells: vec![value; size.len()] }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+
+    pub fn set_mult(&mut self, points: &[Point], value: T) {
+        for p in points {
+            self[p] = value.clone();
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(size: Size, f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..size.len()).map(|i| Point { x: i % size.width, y: i / size.width }).map(f).collect();
+        Self { size, cells }
+    }
+
+    pub fn from_vec(size: Size, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != size.len() {
+            return Err(anyhow!("Grid of size {} needs {} cells, got {}", size, size.len(), cells.len()));
+        }
+
+        Ok(Self { size, cells })
+    }
+
+    pub fn get_size(&self) -> Size {
+        self.size
+    }
+
+    pub fn width(&self) -> usize {
+        self.size.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.size.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.size.contains(p)
+    }
+
+    pub fn to_index(&self, p: &Point) -> usize {
+        p.y * self.size.width + p.x
+    }
+
+    pub fn to_point(&self, index: usize) -> Point {
+        Point { x: index % self.size.width, y: index / self.size.width }
+    }
+
+    pub fn get(&self, p: &Point) -> Option<&T> {
+        if !self.contains(p) {
+            return None;
+        }
+
+        self.cells.get(self.to_index(p))
+    }
+
+    pub fn get_mut(&mut self, p: &Point) -> Option<&mut T> {
+        if !self.contains(p) {
+            return None;
+        }
+
+        let index = self.to_index(p);
+        self.cells.get_mut(index)
+    }
+
+    // Skips the bounds check of the point, only the underlying vec is checked so a point
+    // outside of the grid may wrap into the next row
+    pub fn get_unchecked(&self, p: &Point) -> &T {
+        &self.cells[self.to_index(p)]
+    }
+
+    pub fn get_unchecked_mut(&mut self, p: &Point) -> &mut T {
+        let index = self.to_index(p);
+        &mut self.cells[index]
+    }
+
+    pub fn set(&mut self, p: &Point, value: T) -> Result<()> {
+        let cell = self.get_mut(p).ok_or_else(|| anyhow!("Point {} is outside of the grid", p))?;
+        *cell = value;
+
+        Ok(())
+    }
+
+    // Points that are directly next to the given one and still inside of the grid
+    pub fn neighbours(&self, p: &Point) -> impl Iterator<Item = Point> + '_ {
+        self.size.neighbours(p)
+    }
+
+    // Neighbouring cells, which are two points away because there is a wall in between
+    pub fn cell_neighbours(&self, p: &Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        self.size.cell_neighbours(p)
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.size.width;
+        (0..self.cells.len()).map(move |i| Point { x: i % width, y: i / width })
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.size.width.max(1))
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { size: self.size, cells: self.cells.iter().map(f).collect() }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells
+    }
+}
+
+impl<T> Index<&Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: &Point) -> &T {
+        debug_assert!(self.contains(p), "Point {} is outside of grid {}", p, self.size);
+        self.get_unchecked(p)
+    }
+}
+
+impl<T> IndexMut<&Point> for Grid<T> {
+    fn index_mut(&mut self, p: &Point) -> &mut T {
+        debug_assert!(self.contains(p), "Point {} is outside of grid {}", p, self.size);
+        self.get_unchecked_mut(p)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        &self[&p]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        &mut self[&p]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.iter()
+    }
+}
@@ -0,0 +1,9 @@
+pub mod point_state;
+#[allow(clippy::module_inception)]
+pub mod point;
+pub mod direction;
+pub mod size;
+pub mod grid;
+pub mod bit_grid;
+pub mod dir_grid;
+pub mod sparse_grid;
\ No newline at end of file
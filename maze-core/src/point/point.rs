@@ -1,6 +1,8 @@
-use std::{cmp::Ordering, fmt::Display};
+use std::{cmp::Ordering, fmt::Display, hash::{Hash, Hasher}};
 
-#[derive(Clone, Debug, Copy, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Copy, Eq, Serialize, Deserialize)]
 pub struct Point {
     pub x: usize,
     pub y: usize
@@ -13,22 +15,12 @@ impl Display for Point {
 }
 
 impl Point {
+    // None if the point would end up at a negative coordinate
     pub fn add(&self, x: i32, y: i32) -> Option<Self> {
-        let new_x = self.x as i32 - x;
-        let new_y = self.y as i32 - y;
-
-        let new_x: Result<usize, _> = new_x.try_into();
-        let new_y: Result<usize, _> = new_y.try_into();
-
-        if new_x.is_err() || new_y.is_err() {
-            eprintln!("Could not add because it would resolve in negative point");
-            return None;
-        }
+        let x = usize::try_from(self.x as i32 - x).ok()?;
+        let y = usize::try_from(self.y as i32 - y).ok()?;
 
-        return Some(Point {
-            x: new_x.unwrap(),
-            y: new_y.unwrap()
-        })
+        Some(Point { x, y })
     }
 }
 
@@ -50,3 +42,8 @@ impl PartialEq for Point {
     }
 }
 
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.x, self.y).hash(state);
+    }
+}
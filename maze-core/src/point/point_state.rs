@@ -1,4 +1,4 @@
-use egui::Color32;
+use crate::tools::color::Color;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PointState {
@@ -13,5 +13,7 @@ pub enum VisualIndicator {
     SolvePath,
     Start,
     End,
-    Custom(Color32)
+    Waypoint,
+    Alternative,
+    Custom(Color)
 }
\ No newline at end of file
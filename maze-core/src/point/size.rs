@@ -0,0 +1,112 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+use super::{direction::{Direction, DIRECTION_VEC}, point::Point};
+
+const ADJACENT: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+// Dimensions of a maze in points, walls included
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Size {
+    pub width: usize,
+    pub height: usize
+}
+
+impl Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+// Either a single number for square mazes or `width`x`height`
+impl FromStr for Size {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let parts = s.to_lowercase().split('x').map(|e| e.trim().parse::<usize>()).collect::<Result<Vec<_>, _>>()?;
+        match parts[..] {
+            [size] => Ok(Size::square(size)),
+            [width, height] => Ok(Size { width, height }),
+            _ => Err(anyhow!("Invalid size {}, expected e.g. 51 or 81x41", s))
+        }
+    }
+}
+
+impl Size {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height }
+    }
+
+    pub fn square(size: usize) -> Self {
+        Self { width: size, height: size }
+    }
+
+    // Amount of points
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+
+    pub fn max(&self) -> usize {
+        self.width.max(self.height)
+    }
+
+    pub fn min(&self) -> usize {
+        self.width.min(self.height)
+    }
+
+    // Cells only ever sit on odd coordinates, so both sides have to be odd
+    pub fn is_valid(&self) -> bool {
+        self.width >= 3 && self.height >= 3 && self.width % 2 == 1 && self.height % 2 == 1
+    }
+
+    pub fn to_odd(&self) -> Self {
+        Self { width: self.width | 1, height: self.height | 1 }
+    }
+
+    // Amount of cells on odd coordinates in each direction
+    pub fn cells(&self) -> (usize, usize) {
+        ((self.width.saturating_sub(1)) / 2, (self.height.saturating_sub(1)) / 2)
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn is_border(&self, p: &Point) -> bool {
+        p.x == 0 || p.y == 0 || p.x + 1 == self.width || p.y + 1 == self.height
+    }
+
+    // Points that are directly next to the given one and still inside
+    pub fn neighbours(&self, p: &Point) -> impl Iterator<Item = Point> + '_ {
+        let Point { x, y } = *p;
+        ADJACENT.iter().filter_map(move |(dx, dy)| self.offset(x, y, *dx, *dy))
+    }
+
+    // Neighbouring cells, which are two points away because there is a wall in between
+    pub fn cell_neighbours(&self, p: &Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        let Point { x, y } = *p;
+        DIRECTION_VEC.iter().filter_map(move |info| {
+            self.offset(x, y, info.x as i64, info.y as i64).map(|e| (info.dir, e))
+        })
+    }
+
+    fn offset(&self, x: usize, y: usize, dx: i64, dy: i64) -> Option<Point> {
+        let x = x as i64 + dx;
+        let y = y as i64 + dy;
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return None;
+        }
+
+        Some(Point { x: x as usize, y: y as usize })
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+
+use super::{point::Point, size::Size};
+
+// Grid that only stores the points that were set, every other point reads as None.
+// Entries are kept by their row major index, so iterating goes row by row like a Grid does.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseGrid<T> {
+    size: Size,
+    cells: BTreeMap<usize, T>
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self { size: Size::default(), cells: BTreeMap::new() }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(size: Size) -> Self {
+        Self { size, cells: BTreeMap::new() }
+    }
+
+    pub fn get_size(&self) -> Size {
+        self.size
+    }
+
+    // Amount of points that are set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.size.contains(p)
+    }
+
+    pub fn get(&self, p: &Point) -> Option<&T> {
+        if !self.contains(p) {
+            return None;
+        }
+
+        self.cells.get(&self.to_index(p))
+    }
+
+    // Panics in debug builds if the point is outside of the grid
+    pub fn insert(&mut self, p: Point, value: T) {
+        debug_assert!(self.contains(&p), "Point {} is outside of grid {}", p, self.size);
+        self.cells.insert(self.to_index(&p), value);
+    }
+
+    pub fn set(&mut self, p: &Point, value: T) -> Result<()> {
+        if !self.contains(p) {
+            return Err(anyhow!("Point {} is outside of the grid", p));
+        }
+
+        self.insert(*p, value);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, p: &Point) -> Option<T> {
+        self.cells.remove(&self.to_index(p))
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.size.width;
+        self.cells.iter().map(move |(i, e)| (Point { x: i % width, y: i / width }, e))
+    }
+
+    fn to_index(&self, p: &Point) -> usize {
+        p.y * self.size.width + p.x
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    pub fn set_mult(&mut self, points: &[Point], value: T) {
+        for p in points {
+            self.insert(*p, value.clone());
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::{fs, path::Path};
+
+use anyhow::Result;
+use image::ImageFormat;
+
+use crate::{
+    analysis::metrics::{analyze, MazeMetrics},
+    events::event::{overlay_events, MazeEvent},
+    export::{pdf::{mazes_to_pdf, PdfOptions}, svg::{maze_to_svg, SvgOptions}},
+    generators::{decimate::decimate_maze, generate::generate_as},
+    graph::graph::MazeGraph,
+    point::{point::Point, point_state::VisualIndicator, size::Size},
+    solve::{multi_agent::Agent, placement::random_cells, solve::{solve, SolveAlgorithm, SolveOptions}},
+    tools::{
+        consts::{check_size, BitMaze, Maze, MazeOptions, VisualOverwrites},
+        binary::{binary_to_maze, maze_to_binary, BinaryHeader},
+        image::{img_to_maze, maze_to_img, ImportOptions},
+        text::{maze_to_text, text_to_maze, TextStyle},
+        math::mix_seed,
+        options::MazeData,
+        render::{draw_path, leg_color},
+        storage::MazeStorage,
+    },
+};
+
+// Everything that is left after a maze was generated and solved. Huge mazes can be kept in a BitMaze,
+// anything that shows or saves the run besides the .maze format needs a Maze though.
+pub struct MazeRun<M: MazeStorage = Maze> {
+    pub maze: M,
+    pub size: Size,
+    // Options the maze was generated with, loaded mazes only know their size
+    pub maze_options: MazeOptions,
+    pub options: SolveOptions,
+    pub legs: Vec<Vec<Point>>,
+    pub visual_overwrites: VisualOverwrites,
+    // Only set if MazeOptions::analyze was set or the run was loaded with its metrics
+    pub metrics: Option<MazeMetrics>,
+}
+
+impl<M: MazeStorage> MazeRun<M> {
+    pub fn generate(data: &MazeData, algorithm: SolveAlgorithm) -> Result<Self> {
+        check_size(data);
+
+        let mut maze = generate_as(data)?;
+
+        decimate_maze(data, &mut maze)?;
+        Self::solve(data, maze, algorithm)
+    }
+
+    // Solves a maze that was not generated by us, e.g. one read by `load_maze`
+    pub fn load(data: &MazeData, maze: M, algorithm: SolveAlgorithm) -> Result<Self> {
+        if data.has_observers() {
+            data.emit(MazeEvent::Loaded(maze.to_maze()))?;
+        }
+        Self::solve(data, maze, algorithm)
+    }
+
+    pub fn solve(data: &MazeData, mut maze: M, algorithm: SolveAlgorithm) -> Result<Self> {
+        let size = maze.get_size();
+        let opt = data.get_opt();
+        let mut options = SolveOptions::from_placement(&mut maze, opt.seed, algorithm, &opt.placement)?;
+        options.use_graph = opt.use_graph;
+        if algorithm == SolveAlgorithm::Waypoints {
+            // Own stream, otherwise the waypoints would be the same cells a random placement picks
+            let seed = mix_seed(opt.seed, &[1]);
+            options.waypoints = random_cells(&maze, seed, opt.waypoints, &[options.start, options.end])?;
+        }
+        if algorithm == SolveAlgorithm::MultiAgent {
+            let others = opt.agents.saturating_sub(1);
+            let seed = mix_seed(opt.seed, &[2]);
+            let cells = random_cells(&maze, seed, others * 2, &[options.start, options.end])?;
+
+            options.agents = vec![Agent { start: options.start, end: options.end }];
+            options.agents.extend(cells.chunks(2).map(|e| Agent { start: e[0], end: e[1] }));
+        }
+        let SolveOptions { start, end, .. } = options;
+
+        let (legs, mut visual_overwrites) = solve(&mut maze, data, &options)?;
+        for path in &legs {
+            data.emit(MazeEvent::PathFound(path.clone()))?;
+        }
+
+        draw_solution(&legs, &options, &mut visual_overwrites)?;
+
+        let metrics = if opt.analyze { Some(analyze(&maze, &start, &end)?) } else { None };
+        if data.has_observers() {
+            data.emit_all(overlay_events(&visual_overwrites))?;
+            data.emit(MazeEvent::Finished)?;
+        }
+
+        Ok(Self { maze, size, maze_options: opt, options, legs, visual_overwrites, metrics })
+    }
+
+    // Sends the maze and its solution to the observers without solving it again, e.g. after `load_run`
+    pub fn show(&self, data: &MazeData) -> Result<()> {
+        if data.has_observers() {
+            data.emit(MazeEvent::Loaded(self.maze.to_maze()))?;
+        }
+        for path in &self.legs {
+            data.emit(MazeEvent::PathFound(path.clone()))?;
+        }
+
+        if data.has_observers() {
+            data.emit_all(overlay_events(&self.visual_overwrites))?;
+            data.emit(MazeEvent::Finished)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl MazeRun {
+    // The format is picked by the file extension, everything unknown is saved as PNG
+    pub fn save(&self, save_path: &str) -> Result<()> {
+        let SolveOptions { start, end, .. } = self.options;
+        let extension = Path::new(save_path).extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase());
+        match extension.as_deref() {
+            Some("dot") | Some("graphml") => {
+                let graph = MazeGraph::from_maze(&self.maze, &[start, end])?;
+                let out = if extension.as_deref() == Some("dot") { graph.to_dot() } else { graph.to_graphml() };
+
+                fs::write(save_path, out)?;
+            }
+            Some("svg") => self.save_svg(save_path, &SvgOptions::default())?,
+            Some("txt") | Some("utf8") => {
+                let style = if extension.as_deref() == Some("txt") { TextStyle::Ascii } else { TextStyle::Unicode };
+                fs::write(save_path, maze_to_text(&self.maze, &self.legs, Some((start, end)), style)?)?;
+            }
+            Some("json") => fs::write(save_path, self.to_json()?)?,
+            Some("maze") => {
+                let header = BinaryHeader::from_options(&self.maze_options, Some((start, end)), true);
+                fs::write(save_path, maze_to_binary(&self.maze, &header)?)?;
+            }
+            Some("pdf") => fs::write(save_path, mazes_to_pdf(std::slice::from_ref(self), &PdfOptions::default())?)?,
+            _ => {
+                let out = maze_to_img(&self.maze, &self.visual_overwrites)?;
+                out.save_with_format(save_path, ImageFormat::Png)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn save_svg(&self, save_path: &str, options: &SvgOptions) -> Result<()> {
+        let SolveOptions { start, end, .. } = self.options;
+        let out = maze_to_svg(&self.maze, &self.legs, Some((start, end)), options)?;
+
+        fs::write(save_path, out)?;
+        Ok(())
+    }
+}
+
+impl MazeRun<BitMaze> {
+    // Only .maze files are written straight from the bits, every other format unpacks the maze first
+    pub fn save(&self, save_path: &str) -> Result<()> {
+        let extension = Path::new(save_path).extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase());
+        if extension.as_deref() != Some("maze") {
+            return self.to_maze_run().save(save_path);
+        }
+
+        let SolveOptions { start, end, .. } = self.options;
+        let header = BinaryHeader::from_options(&self.maze_options, Some((start, end)), true);
+        fs::write(save_path, maze_to_binary(&self.maze, &header)?)?;
+
+        Ok(())
+    }
+
+    pub fn to_maze_run(&self) -> MazeRun {
+        MazeRun {
+            maze: self.maze.to_maze(),
+            size: self.size,
+            maze_options: self.maze_options.clone(),
+            options: self.options.clone(),
+            legs: self.legs.clone(),
+            visual_overwrites: self.visual_overwrites.clone(),
+            metrics: self.metrics.clone(),
+        }
+    }
+}
+
+// Paints the legs, start, end, waypoints and agents on top of what the solver left behind
+pub fn draw_solution(legs: &[Vec<Point>], options: &SolveOptions, visual_overwrites: &mut VisualOverwrites) -> Result<()> {
+    let SolveOptions { start, end, .. } = *options;
+    for (i, path) in legs.iter().enumerate() {
+        let indicator = if legs.len() == 1 {
+            VisualIndicator::SolvePath
+        } else {
+            VisualIndicator::Custom(leg_color(i))
+        };
+        draw_path(path, visual_overwrites, indicator)?;
+    }
+
+    visual_overwrites.set(&start, VisualIndicator::Start)?;
+    visual_overwrites.set(&end, VisualIndicator::End)?;
+    visual_overwrites.set_mult(&options.waypoints, VisualIndicator::Waypoint);
+    for agent in &options.agents {
+        visual_overwrites.set(&agent.start, VisualIndicator::Start)?;
+        visual_overwrites.set(&agent.end, VisualIndicator::End)?;
+    }
+    if let Some((start_opening, end_opening)) = options.placement.openings(visual_overwrites.get_size()) {
+        visual_overwrites.set_mult(&[start, end], VisualIndicator::SolvePath);
+        visual_overwrites.set(&start_opening, VisualIndicator::Start)?;
+        visual_overwrites.set(&end_opening, VisualIndicator::End)?;
+    }
+
+    Ok(())
+}
+
+// Restores a run saved as .json including its solution, other formats only contain the maze
+pub fn load_run(path: &str) -> Result<Option<MazeRun>> {
+    let extension = Path::new(path).extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase());
+    if extension.as_deref() != Some("json") {
+        return Ok(None);
+    }
+
+    Ok(Some(MazeRun::from_json(&fs::read_to_string(path)?)?))
+}
+
+// Text files (.txt for ASCII, .utf8 for box-drawing characters) are read as text, .maze files as binary
+// and everything else as an image
+pub fn load_maze(path: &str) -> Result<(Maze, MazeOptions)> {
+    let extension = Path::new(path).extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase());
+    match extension.as_deref() {
+        Some("txt") | Some("utf8") => {
+            let (maze, opt, _) = text_to_maze(&fs::read_to_string(path)?, true)?;
+            Ok((maze, opt))
+        }
+        Some("maze") => {
+            let (maze, opt, _) = binary_to_maze(&fs::read(path)?, true)?;
+            Ok((maze, opt))
+        }
+        Some("json") => {
+            let run = MazeRun::from_json(&fs::read_to_string(path)?)?;
+            Ok((run.maze, run.maze_options))
+        }
+        _ => {
+            let img = image::open(path)?;
+            img_to_maze(&img, &ImportOptions::default())
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::BinaryHeap;
+
+use anyhow::{Result, anyhow};
+
+use crate::{events::event::MazeEvent, tools::{color::Color, consts::VisualOverwrites, matrix::{go_to_dir, get_available_dirs_state, has_passage_between, get_pos_between}, math::{get_dist, linear_dist, points_to_dir}, options::MazeData, storage::MazeStorage}, solve::solve::SolveOptions, point::{dir_grid::DirGrid, sparse_grid::SparseGrid, point_state::{VisualIndicator, PointState}, point::Point, size::Size}};
+
+pub fn a_star<M: MazeStorage>(maze: &mut M, data: &MazeData, options: &SolveOptions) -> Result<(Vec<Point>, VisualOverwrites)> {
+    data.emit(MazeEvent::Message("Running a*".to_string()))?;
+    let SolveOptions { start, end, ..} = options;
+    let size = maze.get_size();
+    let trace_search = data.get_opt().trace_search;
+
+    let mut visual_overwrites = SparseGrid::new(size);
+    // Every cell is reached once at most and only keeps the direction back to the cell it was reached from,
+    // so huge mazes get by with three bits per cell
+    let mut parents = DirGrid::new(size);
+    parents.set(start, None);
+
+    let start_cost = get_dist(start, end) + 1;
+
+    let mut pending = BinaryHeap::new();
+
+    visual_overwrites.insert(*start, VisualIndicator::Start);
+    visual_overwrites.insert(*end, VisualIndicator::End);
+    data.emit_all(vec![
+        MazeEvent::CellVisited(*start, VisualIndicator::Start),
+        MazeEvent::CellVisited(*end, VisualIndicator::End),
+    ])?;
+
+    let mut found = false;
+    pending.push(*start);
+
+    while !pending.is_empty() && !found {
+        let pos = pending.pop().unwrap();
+        data.emit(MazeEvent::FrontierChanged { added: Vec::new(), removed: vec![pos] })?;
+
+        let dirs = get_available_dirs_state(maze, &pos, PointState::Passage)?;
+        for dir in dirs {
+            let neighbor = go_to_dir(&size, &pos, &dir);
+            if neighbor.is_none() { continue; }
+
+            let between_pos = get_pos_between(&size, &pos, &dir)?
+                .ok_or_else(|| anyhow!("There is no point between {} and its neighbor", pos))?;
+            let has_passage = has_passage_between(maze, &pos, &dir)?;
+
+            let has_passage = has_passage.unwrap_or(false);
+            if !has_passage { continue; }
+
+            let neighbor_pos = neighbor.unwrap();
+
+            if !parents.is_set(&neighbor_pos) {
+                let color = ((1.0 - linear_dist(&neighbor_pos, end) / (start_cost as f64)) * 255.0).abs().floor() as u8;
+
+                let indicator = VisualIndicator::Custom(Color::from_rgb(color, 0, 255));
+
+                if trace_search {
+                    visual_overwrites.insert(neighbor_pos, indicator);
+                    visual_overwrites.insert(between_pos, indicator);
+                }
+                parents.set(&neighbor_pos, points_to_dir(&pos, &neighbor_pos));
+                pending.push(neighbor_pos);
+
+                data.emit_all(vec![
+                    MazeEvent::CellVisited(neighbor_pos, indicator),
+                    MazeEvent::CellVisited(between_pos, indicator),
+                    MazeEvent::FrontierChanged { added: vec![neighbor_pos], removed: Vec::new() },
+                ])?;
+            }
+
+            if &neighbor_pos == end {
+                found = true;
+
+                visual_overwrites.insert(*end, VisualIndicator::End);
+                data.emit(MazeEvent::CellVisited(*end, VisualIndicator::End))?;
+                if data.show_anim() {
+                    for _ in 0..100 {
+                        data.emit(MazeEvent::Step)?;
+                    }
+                }
+                break;
+            }
+        }
+
+        for _ in 0..2 {
+            data.emit(MazeEvent::Step)?;
+        }
+    }
+
+    if !found {
+        return Err(anyhow!("Could not solve maze."))
+    }
+
+    Ok((parents_to_path(&parents, &size, end), visual_overwrites))
+}
+
+fn parents_to_path(parents: &DirGrid, size: &Size, end: &Point) -> Vec<Point> {
+    let mut path = vec![*end];
+    let mut curr = *end;
+    while let Some(parent) = parents.get(&curr).and_then(|dir| go_to_dir(size, &curr, &dir)) {
+        path.push(parent);
+        curr = parent;
+    }
+
+    path.reverse();
+    path
+}
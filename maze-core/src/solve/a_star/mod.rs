@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod a_star;
+
+pub use a_star::*;
\ No newline at end of file
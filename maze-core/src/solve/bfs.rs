@@ -0,0 +1,119 @@
+use std::collections::VecDeque;
+
+use anyhow::Result;
+
+use crate::{
+    point::{dir_grid::DirGrid, direction::Direction, grid::Grid, point::Point, size::Size},
+    tools::{direction_data::DirectionData, math::points_to_dir, matrix::get_connected_cells, storage::MazeStorage},
+};
+
+// Result of a breadth first search over the cells of a maze. Every reached cell only keeps the direction
+// to its parent, distances are counted by walking back to the start.
+pub struct BfsTree {
+    size: Size,
+    start: Point,
+    parents: DirGrid,
+    farthest: (Point, u64),
+}
+
+impl BfsTree {
+    pub fn is_reached(&self, p: &Point) -> bool {
+        let same_parity = p.x % 2 == self.start.x % 2 && p.y % 2 == self.start.y % 2;
+        same_parity && self.size.contains(p) && self.parents.is_set(p)
+    }
+
+    pub fn distance_to(&self, p: &Point) -> Option<u64> {
+        self.walk_to(p).map(|e| e.count() as u64 - 1)
+    }
+
+    // Shortest path from the start of the search to `end`, both included
+    pub fn path_to(&self, end: &Point) -> Option<Vec<Point>> {
+        let mut path: Vec<_> = self.walk_to(end)?.collect();
+        path.reverse();
+        Some(path)
+    }
+
+    // Reachable cell that is the most steps away from the start together with its distance,
+    // the first one in row major order if there are several
+    pub fn get_farthest(&self) -> (Point, u64) {
+        self.farthest
+    }
+
+    // Every cell from `end` back to the start, both included
+    fn walk_to(&self, end: &Point) -> Option<impl Iterator<Item = Point> + '_> {
+        if !self.is_reached(end) {
+            return None;
+        }
+
+        let mut next = Some(*end);
+        Some(std::iter::from_fn(move || {
+            let curr = next?;
+            next = self.parents.get(&curr).map(|dir| step(&curr, &dir));
+            Some(curr)
+        }))
+    }
+}
+
+pub fn bfs_tree<M: MazeStorage>(maze: &M, start: &Point) -> Result<BfsTree> {
+    let mut parents = DirGrid::new(maze.get_size());
+    let mut farthest = (*start, 0);
+    let mut pending = VecDeque::new();
+
+    parents.set(start, None);
+    pending.push_back(*start);
+
+    // Goes through the search one distance at a time, so the distances never have to be stored
+    let mut dist = 0;
+    while !pending.is_empty() {
+        farthest = (*pending.iter().min_by_key(|p| (p.y, p.x)).unwrap(), dist);
+        for _ in 0..pending.len() {
+            let pos = pending.pop_front().unwrap();
+            for neighbor in get_connected_cells(maze, &pos)? {
+                if parents.is_set(&neighbor) {
+                    continue;
+                }
+
+                parents.set(&neighbor, points_to_dir(&pos, &neighbor));
+                pending.push_back(neighbor);
+            }
+        }
+
+        dist += 1;
+    }
+
+    Ok(BfsTree { size: maze.get_size(), start: *start, parents, farthest })
+}
+
+// Distance in cell steps from `start` to every reachable cell, None if it can't be reached.
+// Needs a whole Grid, use bfs_tree if only a few distances are needed.
+pub fn bfs_distances<M: MazeStorage>(maze: &M, start: &Point) -> Result<Grid<Option<u64>>> {
+    let mut distances = Grid::new(maze.get_size(), None);
+    let mut pending = VecDeque::new();
+
+    distances[start] = Some(0);
+    pending.push_back(*start);
+
+    while let Some(pos) = pending.pop_front() {
+        let dist = distances[&pos].unwrap_or(0);
+        for neighbor in get_connected_cells(maze, &pos)? {
+            if distances[&neighbor].is_some() {
+                continue;
+            }
+
+            distances[&neighbor] = Some(dist + 1);
+            pending.push_back(neighbor);
+        }
+    }
+
+    Ok(distances)
+}
+
+// Returns the reachable cell that is the most steps away from `start` together with its distance
+pub fn bfs_farthest<M: MazeStorage>(maze: &M, start: &Point) -> Result<(Point, u64)> {
+    Ok(bfs_tree(maze, start)?.get_farthest())
+}
+
+fn step(p: &Point, dir: &Direction) -> Point {
+    let DirectionData { x, y, .. } = dir.to_data();
+    Point { x: (p.x as i32 + x) as usize, y: (p.y as i32 + y) as usize }
+}
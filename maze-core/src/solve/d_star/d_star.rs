@@ -0,0 +1,334 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    events::event::{overlay_events, MazeEvent},
+    point::{grid::Grid, sparse_grid::SparseGrid, direction::Direction, point::Point, point_state::{PointState, VisualIndicator}, size::Size},
+    solve::{placement::check_cell, solve::SolveOptions},
+    tools::{
+        color::Color,
+        consts::VisualOverwrites,
+        math::{get_dist, points_to_dir},
+        matrix::{go_to_dir, has_passage_between},
+        options::MazeData,
+        render::draw_path,
+        storage::MazeStorage,
+    },
+};
+
+use super::{DNode, Key, INFINITY};
+
+// Source of the points the user wants to toggle while D* Lite is solving, e.g. clicks in the GUI
+pub trait WallToggles: Send + Sync {
+    // Returns every point since the last call
+    fn take_toggles(&self) -> Vec<Point>;
+}
+
+// Incremental planner (D* Lite) that searches backwards from the goal, so the agent can keep moving
+// and only the part of the search that is affected by a changed wall has to be repaired.
+pub struct DStarLite {
+    size: Size,
+    // One node per point, only the ones of cells are ever touched
+    nodes: Grid<DNode>,
+    open: BinaryHeap<Reverse<(Key, Point)>>,
+    // Key modifier, grows every time the agent moved before walls changed
+    km: u64,
+    start: Point,
+    last: Point,
+    goal: Point,
+}
+
+impl DStarLite {
+    pub fn new<M: MazeStorage>(maze: &M, start: &Point, goal: &Point) -> Result<Self> {
+        check_cell(maze, start)?;
+        check_cell(maze, goal)?;
+
+        let mut planner = Self {
+            size: maze.get_size(),
+            nodes: Grid::new(maze.get_size(), DNode::default()),
+            open: BinaryHeap::new(),
+            km: 0,
+            start: *start,
+            last: *start,
+            goal: *goal,
+        };
+
+        planner.node_mut(goal).set_rhs(0);
+        let key = planner.calculate_key(goal);
+        planner.push(goal, key);
+
+        Ok(planner)
+    }
+
+    pub fn get_start(&self) -> Point {
+        self.start
+    }
+
+    pub fn get_goal(&self) -> Point {
+        self.goal
+    }
+
+    // Cost of the cheapest known path from the agent to the goal, None if the goal can't be reached
+    pub fn get_distance(&self) -> Option<u64> {
+        let g = self.node(&self.start).get_g();
+        if g == INFINITY { None } else { Some(g) }
+    }
+
+    pub fn move_to(&mut self, p: &Point) {
+        self.start = *p;
+    }
+
+    pub fn compute_shortest_path<M: MazeStorage>(&mut self, maze: &M) -> Result<()> {
+        loop {
+            let top = self.top_key();
+            let start = self.node(&self.start);
+            let start_key = self.calculate_key(&self.start);
+            if top.map(|e| e >= start_key).unwrap_or(true) && start.is_consistent() {
+                break;
+            }
+
+            let Reverse((old_key, u)) = self.open.pop().ok_or_else(|| anyhow!("Open list ran empty"))?;
+            let new_key = self.calculate_key(&u);
+            let node = self.node(&u);
+            if old_key < new_key {
+                self.push(&u, new_key);
+            } else if node.get_g() > node.get_rhs() {
+                let node = self.node_mut(&u);
+                node.set_g(node.get_rhs());
+                node.set_key(None);
+
+                for pred in self.neighbors(&u) {
+                    self.update_vertex(maze, &pred)?;
+                }
+            } else {
+                self.node_mut(&u).set_g(INFINITY);
+
+                self.update_vertex(maze, &u)?;
+                for pred in self.neighbors(&u) {
+                    self.update_vertex(maze, &pred)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Neighbor the agent should walk to next, None if the goal is unreachable or already reached
+    pub fn next_step<M: MazeStorage>(&self, maze: &M) -> Result<Option<Point>> {
+        if self.start == self.goal {
+            return Ok(None);
+        }
+
+        self.best_neighbor(maze, &self.start)
+    }
+
+    // Path the agent would currently take from its position to the goal
+    pub fn planned_path<M: MazeStorage>(&self, maze: &M) -> Result<Vec<Point>> {
+        let mut path = vec![self.start];
+        let mut curr = self.start;
+        while curr != self.goal && path.len() <= self.size.len() {
+            let next = self.best_neighbor(maze, &curr)?;
+            if next.is_none() {
+                break;
+            }
+
+            curr = next.unwrap();
+            path.push(curr);
+        }
+
+        Ok(path)
+    }
+
+    // Flips the given point between wall and passage and marks every cell whose edges changed
+    pub fn toggle_wall<M: MazeStorage>(&mut self, maze: &mut M, p: &Point) -> Result<()> {
+        if !self.size.contains(p) || self.size.is_border(p) {
+            return Err(anyhow!("{} is part of the border or outside of the maze", p));
+        }
+
+        if *p == self.start || *p == self.goal {
+            return Err(anyhow!("Can not place a wall on the agent or the goal"));
+        }
+
+        let state = match maze.get_state(p) {
+            PointState::Wall => PointState::Passage,
+            PointState::Passage => PointState::Wall,
+        };
+        maze.set_state(p, state);
+
+        let affected = match (p.x % 2 == 1, p.y % 2 == 1) {
+            (true, true) => {
+                let mut cells = self.neighbors(p);
+                cells.push(*p);
+                cells
+            }
+            (true, false) => vec![Point { x: p.x, y: p.y - 1 }, Point { x: p.x, y: p.y + 1 }],
+            (false, true) => vec![Point { x: p.x - 1, y: p.y }, Point { x: p.x + 1, y: p.y }],
+            // Points between four cells never connect anything
+            (false, false) => Vec::new(),
+        };
+
+        self.km = self.km.saturating_add(heuristic(&self.last, &self.start));
+        self.last = self.start;
+        for cell in affected {
+            self.update_vertex(maze, &cell)?;
+        }
+
+        Ok(())
+    }
+
+    fn update_vertex<M: MazeStorage>(&mut self, maze: &M, u: &Point) -> Result<()> {
+        if *u != self.goal {
+            let mut rhs = INFINITY;
+            for n in self.neighbors(u) {
+                let cost = self.cost(maze, u, &n)?.saturating_add(self.node(&n).get_g());
+                rhs = rhs.min(cost);
+            }
+
+            self.node_mut(u).set_rhs(rhs);
+        }
+
+        if self.node(u).is_consistent() {
+            self.node_mut(u).set_key(None);
+        } else {
+            let key = self.calculate_key(u);
+            self.push(u, key);
+        }
+
+        Ok(())
+    }
+
+    fn best_neighbor<M: MazeStorage>(&self, maze: &M, p: &Point) -> Result<Option<Point>> {
+        let mut best = None;
+        let mut best_cost = INFINITY;
+        for n in self.neighbors(p) {
+            let cost = self.cost(maze, p, &n)?.saturating_add(self.node(&n).get_g());
+            if cost < best_cost {
+                best_cost = cost;
+                best = Some(n);
+            }
+        }
+
+        Ok(best)
+    }
+
+    fn calculate_key(&self, p: &Point) -> Key {
+        let node = self.node(p);
+        let min = node.get_g().min(node.get_rhs());
+
+        (min.saturating_add(heuristic(&self.start, p)).saturating_add(self.km), min)
+    }
+
+    // Entries of the heap are never removed, outdated ones are skipped here instead
+    fn top_key(&mut self) -> Option<Key> {
+        while let Some(Reverse((key, p))) = self.open.peek() {
+            if self.node(p).get_key() == Some(*key) {
+                return Some(*key);
+            }
+
+            self.open.pop();
+        }
+
+        None
+    }
+
+    fn push(&mut self, p: &Point, key: Key) {
+        self.node_mut(p).set_key(Some(key));
+        self.open.push(Reverse((key, *p)));
+    }
+
+    fn cost<M: MazeStorage>(&self, maze: &M, from: &Point, to: &Point) -> Result<u64> {
+        if !maze.is_passage(from) || !maze.is_passage(to) {
+            return Ok(INFINITY);
+        }
+
+        let dir = points_to_dir(to, from).ok_or_else(|| anyhow!("{} and {} are not neighbors", from, to))?;
+        let has_passage = has_passage_between(maze, from, &dir)?.unwrap_or(false);
+
+        Ok(if has_passage { 1 } else { INFINITY })
+    }
+
+    fn neighbors(&self, p: &Point) -> Vec<Point> {
+        Direction::all()
+            .iter()
+            .filter_map(|dir| go_to_dir(&self.size, p, dir))
+            .collect()
+    }
+
+    fn node(&self, p: &Point) -> DNode {
+        self.nodes[p]
+    }
+
+    fn node_mut(&mut self, p: &Point) -> &mut DNode {
+        &mut self.nodes[p]
+    }
+}
+
+// Manhattan distance in cell steps, cells are two points apart
+fn heuristic(a: &Point, b: &Point) -> u64 {
+    get_dist(a, b) / 2
+}
+
+// Walks an agent from start to end and repairs its plan whenever walls are toggled in the meantime
+pub fn d_star_lite<M: MazeStorage>(maze: &mut M, data: &MazeData, options: &SolveOptions) -> Result<(Vec<Point>, VisualOverwrites)> {
+    data.emit(MazeEvent::Message("Running D* Lite".to_string()))?;
+    let size = maze.get_size();
+    let SolveOptions { start, end, .. } = options;
+
+    // Clicks that happened before solving started are not meant for the agent
+    data.take_wall_toggles();
+
+    let mut planner = DStarLite::new(maze, start, end)?;
+    planner.compute_shortest_path(maze)?;
+
+    let mut trail = vec![*start];
+    let mut visual_overwrites = SparseGrid::new(size);
+    while planner.get_start() != planner.get_goal() {
+        let toggles = data.take_wall_toggles();
+        if !toggles.is_empty() {
+            for p in toggles {
+                if let Err(e) = planner.toggle_wall(maze, &p) {
+                    data.emit(MazeEvent::Message(format!("Could not toggle {}: {}", p, e)))?;
+                    continue;
+                }
+
+                let event = match maze.get_state(&p) {
+                    PointState::Wall => MazeEvent::CellWalled(p),
+                    PointState::Passage => MazeEvent::CellCarved(p),
+                };
+                data.emit(event)?;
+            }
+
+            planner.compute_shortest_path(maze)?;
+            match planner.get_distance() {
+                Some(dist) => data.emit(MazeEvent::Message(format!("Replanned, {} steps left", dist)))?,
+                None => return Err(anyhow!("{} can not be reached anymore", end)),
+            }
+        }
+
+        let next = planner
+            .next_step(maze)?
+            .ok_or_else(|| anyhow!("{} can not be reached anymore", end))?;
+        planner.move_to(&next);
+        trail.push(next);
+
+        if data.show_anim() {
+            visual_overwrites = SparseGrid::new(size);
+            let planned = planner.planned_path(maze)?;
+            draw_path(&planned, &mut visual_overwrites, VisualIndicator::Custom(Color::from_rgb(0, 160, 255)))?;
+            draw_path(&trail, &mut visual_overwrites, VisualIndicator::SolvePath)?;
+
+            visual_overwrites.insert(*start, VisualIndicator::Start);
+            visual_overwrites.insert(*end, VisualIndicator::End);
+            visual_overwrites.insert(next, VisualIndicator::Match);
+            if data.has_observers() {
+                data.emit_all(overlay_events(&visual_overwrites))?;
+                for _ in 0..2 {
+                    data.emit(MazeEvent::Step)?;
+                }
+            }
+        }
+    }
+
+    Ok((trail, visual_overwrites))
+}
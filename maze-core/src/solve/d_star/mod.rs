@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod d_star;
+mod node;
+
+pub use node::*;
+pub use d_star::*;
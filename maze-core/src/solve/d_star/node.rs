@@ -0,0 +1,54 @@
+pub const INFINITY: u64 = u64::MAX;
+
+// Priority of a node in the open list, compared lexicographically
+pub type Key = (u64, u64);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DNode {
+    // Current estimate of the distance to the goal
+    g: u64,
+    // One step lookahead of g, the node is consistent if both are equal
+    rhs: u64,
+    // Key this node is queued with, None if it isn't in the open list
+    key: Option<Key>,
+}
+
+impl Default for DNode {
+    fn default() -> Self {
+        Self {
+            g: INFINITY,
+            rhs: INFINITY,
+            key: None,
+        }
+    }
+}
+
+impl DNode {
+    pub fn get_g(&self) -> u64 {
+        self.g
+    }
+
+    pub fn set_g(&mut self, g: u64) {
+        self.g = g;
+    }
+
+    pub fn get_rhs(&self) -> u64 {
+        self.rhs
+    }
+
+    pub fn set_rhs(&mut self, rhs: u64) {
+        self.rhs = rhs;
+    }
+
+    pub fn get_key(&self) -> Option<Key> {
+        self.key
+    }
+
+    pub fn set_key(&mut self, key: Option<Key>) {
+        self.key = key;
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.g == self.rhs
+    }
+}
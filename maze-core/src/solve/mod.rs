@@ -0,0 +1,10 @@
+pub mod a_star;
+pub mod bfs;
+pub mod d_star;
+pub mod multi_agent;
+pub mod placement;
+pub mod reduced;
+pub mod shortest_paths;
+#[allow(clippy::module_inception)]
+pub mod solve;
+pub mod waypoints;
@@ -0,0 +1,223 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    events::event::{overlay_events, MazeEvent},
+    point::{sparse_grid::SparseGrid, point::Point, point_state::VisualIndicator},
+    tools::{
+        consts::VisualOverwrites,
+        matrix::get_connected_cells,
+        options::MazeData,
+        render::{draw_path, leg_color},
+        storage::MazeStorage,
+    },
+};
+
+use super::{bfs::bfs_distances, placement::check_cell, solve::SolveOptions};
+
+// Space time states one agent may expand per cell of the maze before its search gives up. Agents that
+// are locked out of their goal would otherwise search every cell at every time step up to the horizon.
+const STATES_PER_CELL: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Agent {
+    pub start: Point,
+    pub end: Point,
+}
+
+// Cells and moves that are already taken by agents with a higher priority
+#[derive(Default)]
+struct Reservations {
+    vertices: HashSet<(Point, usize)>,
+    edges: HashSet<(Point, Point, usize)>,
+    // Agents stay on their goal once they arrived, so the cell is blocked from that time on
+    parked: HashMap<Point, usize>,
+    // Last time step any agent passes a cell
+    last_visit: HashMap<Point, usize>,
+}
+
+impl Reservations {
+    fn is_free(&self, from: &Point, to: &Point, t: usize) -> bool {
+        if self.vertices.contains(&(*to, t)) {
+            return false;
+        }
+
+        if self.parked.get(to).map(|parked| t >= *parked).unwrap_or(false) {
+            return false;
+        }
+
+        // Two agents can't swap places by walking through each other
+        !self.edges.contains(&(*to, *from, t))
+    }
+
+    fn can_park(&self, p: &Point, t: usize) -> bool {
+        self.last_visit.get(p).map(|last| *last < t).unwrap_or(true)
+    }
+
+    fn reserve(&mut self, path: &[Point]) {
+        for (t, p) in path.iter().enumerate() {
+            self.vertices.insert((*p, t));
+
+            let last = self.last_visit.entry(*p).or_insert(t);
+            *last = (*last).max(t);
+
+            if t > 0 {
+                self.edges.insert((path[t - 1], *p, t));
+            }
+        }
+
+        if let Some(end) = path.last() {
+            self.parked.insert(*end, path.len() - 1);
+        }
+    }
+}
+
+// Prioritized planning: every agent is routed with a space time A* in the given order and has to
+// avoid everything the agents before it reserved. Returned paths have one point per time step,
+// agents that arrived early are padded so all of them have the same length.
+pub fn plan_agents<M: MazeStorage>(maze: &M, agents: &[Agent]) -> Result<Vec<Vec<Point>>> {
+    let size = maze.get_size();
+    for (i, agent) in agents.iter().enumerate() {
+        check_cell(maze, &agent.start)?;
+        check_cell(maze, &agent.end)?;
+
+        let others = &agents[..i];
+        if others.iter().any(|e| e.start == agent.start || e.end == agent.end) {
+            return Err(anyhow!("Agent {} shares its start or end with another agent", i));
+        }
+    }
+
+    let (cells_x, cells_y) = size.cells();
+    let cell_count = cells_x * cells_y;
+    let horizon = cell_count * 2 + agents.len();
+
+    let mut reservations = Reservations::default();
+    for agent in agents {
+        reservations.vertices.insert((agent.start, 0));
+    }
+
+    let mut paths = Vec::with_capacity(agents.len());
+    for (i, agent) in agents.iter().enumerate() {
+        // Start cells of agents that weren't planned yet are occupied at the very beginning
+        reservations.vertices.remove(&(agent.start, 0));
+
+        let path = space_time_a_star(maze, agent, &reservations, horizon, cell_count * STATES_PER_CELL)?
+            .ok_or_else(|| anyhow!("Agent {} could not find a collision free path from {} to {}", i, agent.start, agent.end))?;
+
+        reservations.reserve(&path);
+        paths.push(path);
+    }
+
+    let length = paths.iter().map(|e| e.len()).max().unwrap_or(0);
+    for path in paths.iter_mut() {
+        let end = *path.last().unwrap();
+        path.resize(length, end);
+    }
+
+    Ok(paths)
+}
+
+fn space_time_a_star<M: MazeStorage>(maze: &M, agent: &Agent, reservations: &Reservations, horizon: usize, max_states: usize) -> Result<Option<Vec<Point>>> {
+    // Exact distances to the goal ignoring other agents, used as heuristic. Also tells right away if
+    // the goal can't be reached at all, no matter where the other agents are.
+    let to_goal = bfs_distances(maze, &agent.end)?;
+    let h = |p: &Point| to_goal[p].map(|e| e as usize);
+
+    let start_h = h(&agent.start).ok_or_else(|| anyhow!("{} can not be reached from {}", agent.end, agent.start))?;
+
+    let mut parents: HashMap<(Point, usize), Point> = HashMap::new();
+    let mut closed = HashSet::new();
+    let mut pending = BinaryHeap::new();
+    pending.push(Reverse((start_h, 0, agent.start)));
+
+    while let Some(Reverse((_, t, pos))) = pending.pop() {
+        if !closed.insert((pos, t)) {
+            continue;
+        }
+        if closed.len() > max_states {
+            return Err(anyhow!("Gave up on the path from {} to {} after {} states, other agents keep blocking it", agent.start, agent.end, max_states));
+        }
+
+        if pos == agent.end && reservations.can_park(&pos, t) {
+            let mut path = vec![pos];
+            let mut curr = (pos, t);
+            while let Some(parent) = parents.get(&curr) {
+                path.push(*parent);
+                curr = (*parent, curr.1 - 1);
+            }
+
+            path.reverse();
+            return Ok(Some(path));
+        }
+
+        if t >= horizon {
+            continue;
+        }
+
+        let mut moves = get_connected_cells(maze, &pos)?;
+        moves.push(pos);
+        for next in moves {
+            let state = (next, t + 1);
+            if closed.contains(&state) || !reservations.is_free(&pos, &next, t + 1) {
+                continue;
+            }
+
+            let next_h = match h(&next) {
+                Some(e) => e,
+                None => continue,
+            };
+
+            parents.entry(state).or_insert(pos);
+            pending.push(Reverse((t + 1 + next_h, t + 1, next)));
+        }
+    }
+
+    Ok(None)
+}
+
+// Routes every agent of the options and animates them walking at the same time
+pub fn multi_agent<M: MazeStorage>(maze: &mut M, data: &MazeData, options: &SolveOptions) -> Result<(Vec<Vec<Point>>, VisualOverwrites)> {
+    data.emit(MazeEvent::Message(format!("Running multi agent planning for {} agents", options.agents.len())))?;
+    let size = maze.get_size();
+
+    let paths = plan_agents(maze, &options.agents)?;
+    let steps = paths.first().map(|e| e.len()).unwrap_or(0);
+    data.emit(MazeEvent::Message(format!("All agents arrived after {} steps", steps.saturating_sub(1))))?;
+
+    let mut visual_overwrites = SparseGrid::new(size);
+    if data.show_anim() {
+        for t in 0..steps {
+            visual_overwrites = SparseGrid::new(size);
+            for (i, path) in paths.iter().enumerate() {
+                let mut walked = path[..=t].to_vec();
+                walked.dedup();
+
+                let color = leg_color(i);
+                draw_path(&walked, &mut visual_overwrites, VisualIndicator::Custom(color))?;
+                visual_overwrites.insert(path[t], VisualIndicator::Match);
+            }
+
+            if data.has_observers() {
+                data.emit_all(overlay_events(&visual_overwrites))?;
+                for _ in 0..5 {
+                    data.emit(MazeEvent::Step)?;
+                }
+            }
+        }
+    }
+
+    let legs = paths
+        .into_iter()
+        .map(|mut path| {
+            path.dedup();
+            path
+        })
+        .collect();
+
+    Ok((legs, visual_overwrites))
+}
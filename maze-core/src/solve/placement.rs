@@ -0,0 +1,178 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    point::{point::Point, point_state::PointState, size::Size},
+    tools::storage::MazeStorage,
+};
+
+use super::bfs::bfs_farthest;
+
+const MAX_RANDOM_TRIES: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Placement {
+    // Top left to bottom right cell
+    #[default]
+    Corners,
+    // Same cells as Corners but with an entrance and an exit cut into the outer wall
+    Openings,
+    // Two distinct cells picked with the maze seed
+    Random,
+    Custom(Point, Point),
+    // The two cells with the longest path between them
+    FarthestApart,
+}
+
+impl Placement {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Placement::Corners,
+            Placement::Openings,
+            Placement::Random,
+            Placement::Custom(Point { x: 1, y: 1 }, Point { x: 1, y: 1 }),
+            Placement::FarthestApart,
+        ]
+    }
+
+    // Picks the start and end cell for the given maze. Openings carves the border, so the maze has to be mutable.
+    // Nothing is carved unless both cells are valid.
+    pub fn resolve<M: MazeStorage>(&self, maze: &mut M, seed: u64) -> Result<(Point, Point)> {
+        let size = maze.get_size();
+        if !size.is_valid() {
+            return Err(anyhow!("Can not place a start and an end in a maze of {}", size));
+        }
+
+        let corners = (Point { x: 1, y: 1 }, Point { x: size.width - 2, y: size.height - 2 });
+        let (start, end) = match self {
+            Placement::Corners | Placement::Openings => corners,
+            Placement::Random => {
+                let cells = random_cells(maze, seed, 2, &[])?;
+                (cells[0], cells[1])
+            }
+            Placement::Custom(start, end) => (*start, *end),
+            Placement::FarthestApart => {
+                check_cell(maze, &corners.0)?;
+
+                let (start, _) = bfs_farthest(maze, &corners.0)?;
+                let (end, _) = bfs_farthest(maze, &start)?;
+                (start, end)
+            }
+        };
+
+        check_cell(maze, &start)?;
+        check_cell(maze, &end)?;
+        if start == end {
+            return Err(anyhow!("Start and end can not be the same cell ({})", start));
+        }
+
+        if let Some((start_opening, end_opening)) = self.openings(size) {
+            maze.set_state(&start_opening, PointState::Passage);
+            maze.set_state(&end_opening, PointState::Passage);
+        }
+
+        Ok((start, end))
+    }
+
+    // Placement of an imported maze with start and end markers, a missing marker falls back to its corner.
+    // Mazes saved with Openings are recognized by their border, the markers are either the corners or the
+    // openings themselves depending on the format.
+    pub fn from_markers<M: MazeStorage>(maze: &M, start: Option<Point>, end: Option<Point>) -> Option<Self> {
+        let size = maze.get_size();
+        if (start.is_none() && end.is_none()) || !size.is_valid() {
+            return None;
+        }
+
+        let corners = (Point { x: 1, y: 1 }, Point { x: size.width - 2, y: size.height - 2 });
+        let markers = (start.unwrap_or(corners.0), end.unwrap_or(corners.1));
+
+        let openings = Placement::Openings.openings(size).unwrap();
+        let is_open = maze.is_passage(&openings.0) && maze.is_passage(&openings.1);
+        if is_open && (markers == corners || markers == openings) {
+            return Some(Placement::Openings);
+        }
+
+        Some(Placement::Custom(markers.0, markers.1))
+    }
+
+    // Start and end the markers of an imported maze stand for, see from_markers
+    pub fn markers(&self, size: Size) -> Option<(Point, Point)> {
+        match self {
+            Placement::Openings => Some((Point { x: 1, y: 1 }, Point { x: size.width - 2, y: size.height - 2 })),
+            Placement::Custom(start, end) => Some((*start, *end)),
+            _ => None,
+        }
+    }
+
+    // Border points that are opened up next to the start and end cell
+    pub fn openings(&self, size: Size) -> Option<(Point, Point)> {
+        if *self != Placement::Openings {
+            return None;
+        }
+
+        Some((Point { x: 1, y: 0 }, Point { x: size.width - 2, y: size.height - 1 }))
+    }
+}
+
+impl Display for Placement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let e = match self {
+            Self::Corners => "Corners",
+            Self::Openings => "Openings",
+            Self::Random => "Random",
+            Self::Custom(..) => "Custom",
+            Self::FarthestApart => "Farthest Apart",
+        };
+
+        write!(f, "{}", e)
+    }
+}
+
+// Passages are only on odd points, everything else would break the solvers
+pub fn check_cell<M: MazeStorage>(maze: &M, p: &Point) -> Result<()> {
+    let size = maze.get_size();
+    if !size.contains(p) {
+        return Err(anyhow!("{} is outside of the maze (size {})", p, size));
+    }
+
+    if p.x.is_multiple_of(2) || p.y.is_multiple_of(2) {
+        return Err(anyhow!("{} is not a cell, coordinates have to be odd", p));
+    }
+
+    if !maze.is_passage(p) {
+        return Err(anyhow!("{} is not a passage", p));
+    }
+
+    Ok(())
+}
+
+// Picks `count` distinct passage cells that are not part of `exclude`
+pub fn random_cells<M: MazeStorage>(maze: &M, seed: u64, count: usize, exclude: &[Point]) -> Result<Vec<Point>> {
+    let size = maze.get_size();
+    let (cells_x, cells_y) = size.cells();
+    let mut seeder = StdRng::seed_from_u64(seed);
+
+    let mut cells: Vec<Point> = Vec::with_capacity(count);
+    while cells.len() < count {
+        let mut found = None;
+        for _ in 0..MAX_RANDOM_TRIES {
+            let x = seeder.gen_range(0..cells_x) * 2 + 1;
+            let y = seeder.gen_range(0..cells_y) * 2 + 1;
+            let p = Point { x, y };
+
+            if maze.is_passage(&p) && !cells.contains(&p) && !exclude.contains(&p) {
+                found = Some(p);
+                break;
+            }
+        }
+
+        let found = found.ok_or_else(|| anyhow!("Could not find a random passage cell"))?;
+        cells.push(found);
+    }
+
+    Ok(cells)
+}
@@ -0,0 +1,42 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    events::event::{overlay_events, MazeEvent},
+    graph::graph::MazeGraph,
+    point::{sparse_grid::SparseGrid, point::Point, point_state::VisualIndicator},
+    tools::{
+        color::Color,
+        consts::VisualOverwrites,
+        options::MazeData,
+        storage::MazeStorage,
+    },
+};
+
+use super::solve::SolveOptions;
+
+// Solves on the junction graph instead of the single cells, corridors are skipped in one step
+pub fn reduced_graph<M: MazeStorage>(maze: &mut M, data: &MazeData, options: &SolveOptions) -> Result<(Vec<Point>, VisualOverwrites)> {
+    data.emit(MazeEvent::Message("Running a* on the reduced graph".to_string()))?;
+    let size = maze.get_size();
+    let SolveOptions { start, end, .. } = options;
+
+    let graph = MazeGraph::from_maze(maze, &[*start, *end])?;
+    let (cells_x, cells_y) = size.cells();
+    data.emit(MazeEvent::Message(format!("Reduced {} cells to {} nodes and {} corridors", cells_x * cells_y, graph.get_nodes().len(), graph.get_corridors().len())))?;
+
+    let mut visual_overwrites = SparseGrid::new(size);
+    visual_overwrites.set_mult(graph.get_nodes(), VisualIndicator::Custom(Color::from_rgb(0, 160, 255)));
+    visual_overwrites.insert(*start, VisualIndicator::Start);
+    visual_overwrites.insert(*end, VisualIndicator::End);
+    if data.has_observers() {
+        data.emit_all(overlay_events(&visual_overwrites))?;
+        data.emit(MazeEvent::Step)?;
+    }
+
+    let (length, path) = graph
+        .shortest_path(start, end)?
+        .ok_or_else(|| anyhow!("There is no path from {} to {}", start, end))?;
+    data.emit(MazeEvent::Message(format!("Found a path with a length of {}", length)))?;
+
+    Ok((path, visual_overwrites))
+}
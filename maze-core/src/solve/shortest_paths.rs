@@ -0,0 +1,160 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    events::event::{overlay_events, MazeEvent},
+    point::{grid::Grid, sparse_grid::SparseGrid, point::Point, point_state::VisualIndicator},
+    tools::{
+        consts::VisualOverwrites,
+        matrix::get_connected_cells,
+        options::MazeData,
+        render::draw_path,
+        storage::MazeStorage,
+    },
+};
+
+use super::{bfs::bfs_distances, solve::SolveOptions};
+
+pub struct ShortestPaths {
+    // Amount of cell steps of a single shortest path
+    pub length: u64,
+    // Number of distinct shortest paths. Saturates, u128::MAX means there are at least that many
+    pub count: u128,
+    // Every cell that is part of at least one shortest path
+    pub cells: Vec<Point>,
+}
+
+impl ShortestPaths {
+    // The real count is u128::MAX or more
+    pub fn is_saturated(&self) -> bool {
+        self.count == u128::MAX
+    }
+}
+
+// Cells that lie on a shortest path are exactly the ones where the distance from the start
+// and the distance to the end add up to the length of the shortest path
+struct ShortestDag {
+    from_start: Grid<Option<u64>>,
+    from_end: Grid<Option<u64>>,
+    length: u64,
+}
+
+impl ShortestDag {
+    fn new<M: MazeStorage>(maze: &M, start: &Point, end: &Point) -> Result<Self> {
+        let from_start = bfs_distances(maze, start)?;
+        let from_end = bfs_distances(maze, end)?;
+
+        let length = from_start[end]
+            .ok_or_else(|| anyhow!("There is no path from {} to {}", start, end))?;
+
+        Ok(Self { from_start, from_end, length })
+    }
+
+    fn contains(&self, p: &Point) -> bool {
+        match (self.from_start[p], self.from_end[p]) {
+            (Some(s), Some(e)) => s + e == self.length,
+            _ => false,
+        }
+    }
+
+    // Neighbors that are one step further along a shortest path
+    fn successors<M: MazeStorage>(&self, maze: &M, p: &Point) -> Result<Vec<Point>> {
+        let dist = self.from_start[p];
+        let cells = get_connected_cells(maze, p)?
+            .into_iter()
+            .filter(|n| self.contains(n) && self.from_start[n] == dist.map(|d| d + 1))
+            .collect();
+
+        Ok(cells)
+    }
+
+    fn summarize<M: MazeStorage>(&self, maze: &M, start: &Point, end: &Point) -> Result<ShortestPaths> {
+        let mut cells: Vec<Point> = self.from_start.points()
+            .filter(|p| self.contains(p))
+            .collect();
+
+        // Walking the cells by distance makes sure every predecessor is counted before its successors
+        cells.sort_by_key(|p| self.from_start[p]);
+
+        let mut counts = Grid::new(maze.get_size(), 0u128);
+        counts[start] = 1;
+        for p in &cells {
+            let count = counts[p];
+            for next in self.successors(maze, p)? {
+                let next_count = counts[&next].saturating_add(count);
+                counts[&next] = next_count;
+            }
+        }
+
+        Ok(ShortestPaths {
+            length: self.length,
+            count: counts[end],
+            cells,
+        })
+    }
+
+    fn enumerate<M: MazeStorage>(&self, maze: &M, start: &Point, end: &Point, limit: usize) -> Result<Vec<Vec<Point>>> {
+        let mut paths = Vec::new();
+        let mut path = vec![*start];
+        let mut pending = vec![self.successors(maze, start)?];
+        while paths.len() < limit {
+            let options = match pending.last_mut() {
+                Some(options) => options,
+                None => break,
+            };
+
+            if path.last() == Some(end) {
+                paths.push(path.clone());
+            }
+
+            match options.pop() {
+                Some(next) => {
+                    path.push(next);
+                    pending.push(self.successors(maze, &next)?);
+                }
+                None => {
+                    path.pop();
+                    pending.pop();
+                }
+            }
+        }
+
+        Ok(paths)
+    }
+}
+
+pub fn shortest_paths<M: MazeStorage>(maze: &M, start: &Point, end: &Point) -> Result<ShortestPaths> {
+    ShortestDag::new(maze, start, end)?.summarize(maze, start, end)
+}
+
+// Lists up to `limit` distinct shortest paths from start to end, both included
+pub fn enumerate_shortest_paths<M: MazeStorage>(maze: &M, start: &Point, end: &Point, limit: usize) -> Result<Vec<Vec<Point>>> {
+    ShortestDag::new(maze, start, end)?.enumerate(maze, start, end, limit)
+}
+
+// Highlights every cell on a shortest path and returns one of them as the solution
+pub fn all_shortest<M: MazeStorage>(maze: &mut M, data: &MazeData, options: &SolveOptions) -> Result<(Vec<Point>, VisualOverwrites)> {
+    data.emit(MazeEvent::Message("Running all shortest paths".to_string()))?;
+    let SolveOptions { start, end, .. } = options;
+
+    let dag = ShortestDag::new(maze, start, end)?;
+    let res = dag.summarize(maze, start, end)?;
+    let readable_count = if res.is_saturated() { format!("at least {}", u128::MAX) } else { res.count.to_string() };
+    data.emit(MazeEvent::Message(format!("Found {} shortest paths with a length of {}, {} cells are on at least one of them", readable_count, res.length, res.cells.len())))?;
+
+    let mut visual_overwrites = SparseGrid::new(maze.get_size());
+    for p in &res.cells {
+        for next in dag.successors(maze, p)? {
+            draw_path(&[*p, next], &mut visual_overwrites, VisualIndicator::Alternative)?;
+        }
+    }
+
+    visual_overwrites.insert(*start, VisualIndicator::Start);
+    visual_overwrites.insert(*end, VisualIndicator::End);
+    if data.has_observers() {
+        data.emit_all(overlay_events(&visual_overwrites))?;
+        data.emit(MazeEvent::Step)?;
+    }
+
+    let path = dag.enumerate(maze, start, end, 1)?.remove(0);
+    Ok((path, visual_overwrites))
+}
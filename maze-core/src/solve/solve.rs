@@ -0,0 +1,137 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{tools::{consts::VisualOverwrites, options::MazeData, storage::MazeStorage}, point::{sparse_grid::SparseGrid, point::Point, size::Size}};
+
+use super::{a_star::a_star, d_star::d_star_lite, multi_agent::{multi_agent, Agent}, placement::Placement, reduced::reduced_graph, shortest_paths::all_shortest, waypoints::waypoints};
+
+// Returns the solution split up into legs. Waypoints has one leg per stretch between checkpoints and MultiAgent
+// one per agent, every other algorithm only has a single leg.
+pub fn solve<M: MazeStorage>(maze: &mut M, data: &MazeData, options: &SolveOptions) -> Result<(Vec<Vec<Point>>, VisualOverwrites)> {
+    let SolveOptions { algorithm, .. } = options;
+    match algorithm {
+        SolveAlgorithm::AStar => {
+            let (path, visual_overwrites) = if options.use_graph {
+                reduced_graph(maze, data, options)?
+            } else {
+                a_star(maze, data, options)?
+            };
+            Ok((vec![path], visual_overwrites))
+        }
+        SolveAlgorithm::DStarLite => {
+            let (path, visual_overwrites) = d_star_lite(maze, data, options)?;
+            Ok((vec![path], visual_overwrites))
+        }
+        SolveAlgorithm::AllShortest => {
+            let (path, visual_overwrites) = all_shortest(maze, data, options)?;
+            Ok((vec![path], visual_overwrites))
+        }
+        SolveAlgorithm::Waypoints => waypoints(maze, data, options),
+        SolveAlgorithm::MultiAgent => multi_agent(maze, data, options),
+        SolveAlgorithm::None => {
+            let no_visual = SparseGrid::new(maze.get_size());
+            Ok((Vec::new(), no_visual))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolveAlgorithm {
+    None,
+    AStar,
+    DStarLite,
+    AllShortest,
+    Waypoints,
+    MultiAgent
+}
+
+impl  SolveAlgorithm {
+    pub fn all() -> Vec<Self> {
+        vec![SolveAlgorithm::AStar, SolveAlgorithm::DStarLite, SolveAlgorithm::AllShortest, SolveAlgorithm::Waypoints, SolveAlgorithm::MultiAgent, SolveAlgorithm::None]
+    }
+}
+
+impl Display for SolveAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let e = match self {
+            Self::AStar => "A*",
+            Self::DStarLite => "D* Lite",
+            Self::AllShortest => "All Shortest Paths",
+            Self::Waypoints => "Waypoints",
+            Self::MultiAgent => "Multi Agent",
+            Self::None => "None"
+        };
+
+        write!(f, "{}", e)
+    }
+}
+
+// Accepts the names used on the command line, e.g. "a-star" or "multi-agent"
+impl FromStr for SolveAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let e = match s.to_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "astar" | "a*" => Self::AStar,
+            "dstar" | "dstarlite" | "d*lite" => Self::DStarLite,
+            "allshortest" | "allshortestpaths" => Self::AllShortest,
+            "waypoints" => Self::Waypoints,
+            "multiagent" => Self::MultiAgent,
+            "none" => Self::None,
+            _ => return Err(anyhow!("Unknown algorithm {}, possible values are a-star, d-star-lite, all-shortest, waypoints, multi-agent and none", s))
+        };
+
+        Ok(e)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SolveOptions {
+    pub start: Point,
+    pub end: Point,
+    pub algorithm: SolveAlgorithm,
+    pub placement: Placement,
+    // Cells that have to be visited in between start and end, only used by SolveAlgorithm::Waypoints
+    pub waypoints: Vec<Point>,
+    // Agents that are routed at the same time, only used by SolveAlgorithm::MultiAgent
+    pub agents: Vec<Agent>,
+    // Solves on the junction graph with collapsed corridors instead of the single cells
+    pub use_graph: bool
+}
+
+impl SolveOptions {
+    pub fn new(size: Size) -> Self {
+        let start = Point { x: 1, y: 1 };
+        let end = Point {
+            x: size.width - 2,
+            y: size.height - 2,
+        };
+
+        SolveOptions {
+            algorithm: SolveAlgorithm::AStar,
+            start,
+            end,
+            placement: Placement::Corners,
+            waypoints: Vec::new(),
+            agents: Vec::new(),
+            use_graph: false
+        }
+    }
+
+    pub fn from_placement<M: MazeStorage>(maze: &mut M, seed: u64, algorithm: SolveAlgorithm, placement: &Placement) -> Result<Self> {
+        let (start, end) = placement.resolve(maze, seed)?;
+
+        Ok(SolveOptions {
+            algorithm,
+            start,
+            end,
+            placement: *placement,
+            waypoints: Vec::new(),
+            agents: Vec::new(),
+            use_graph: false
+        })
+    }
+}
\ No newline at end of file
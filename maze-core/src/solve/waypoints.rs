@@ -0,0 +1,195 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    events::event::{overlay_events, MazeEvent},
+    point::{sparse_grid::SparseGrid, point::Point, point_state::VisualIndicator},
+    tools::{
+        consts::VisualOverwrites,
+        options::MazeData,
+        storage::MazeStorage,
+    },
+};
+
+use super::{bfs::bfs_tree, solve::SolveOptions};
+
+// Held-Karp needs 2^n * n^2 steps, above this amount of waypoints the heuristic is used instead
+pub const EXACT_LIMIT: usize = 12;
+
+// Visits every waypoint of the options on the shortest route found from start to end.
+// Returns one path per leg, each leg starts where the previous one ended.
+pub fn waypoints<M: MazeStorage>(maze: &mut M, data: &MazeData, options: &SolveOptions) -> Result<(Vec<Vec<Point>>, VisualOverwrites)> {
+    data.emit(MazeEvent::Message(format!("Running waypoints with {} waypoints", options.waypoints.len())))?;
+    let size = maze.get_size();
+    let SolveOptions { start, end, .. } = options;
+
+    let mut visual_overwrites = SparseGrid::new(size);
+    visual_overwrites.set_mult(&options.waypoints, VisualIndicator::Waypoint);
+    visual_overwrites.insert(*start, VisualIndicator::Start);
+    visual_overwrites.insert(*end, VisualIndicator::End);
+
+    // Index 0 is the start, the last index is the end and everything in between are the waypoints
+    let mut stops = vec![*start];
+    stops.extend(options.waypoints.iter());
+    stops.push(*end);
+
+    let mut trees = Vec::with_capacity(stops.len());
+    for stop in &stops {
+        trees.push(bfs_tree(maze, stop)?);
+
+        visual_overwrites.insert(*stop, VisualIndicator::Match);
+        if data.has_observers() {
+            data.emit_all(overlay_events(&visual_overwrites))?;
+            data.emit(MazeEvent::Step)?;
+        }
+    }
+
+    let mut dist = vec![vec![0; stops.len()]; stops.len()];
+    for (i, tree) in trees.iter().enumerate() {
+        for (j, stop) in stops.iter().enumerate() {
+            dist[i][j] = tree
+                .distance_to(stop)
+                .ok_or_else(|| anyhow!("Waypoint {} can not be reached from {}", stop, stops[i]))?;
+        }
+    }
+
+    let order = order_stops(&dist);
+    data.emit(MazeEvent::Message(format!("Route length: {}", route_length(&dist, &order))))?;
+
+    let mut legs = Vec::with_capacity(order.len() - 1);
+    for pair in order.windows(2) {
+        let leg = trees[pair[0]].path_to(&stops[pair[1]]).unwrap();
+        legs.push(leg);
+    }
+
+    Ok((legs, visual_overwrites))
+}
+
+// Orders the stops of the distance matrix so the route from the first to the last one is as short as possible.
+// The returned order always starts with 0 and ends with the last index.
+pub fn order_stops(dist: &[Vec<u64>]) -> Vec<usize> {
+    let waypoint_count = dist.len().saturating_sub(2);
+    if waypoint_count <= EXACT_LIMIT {
+        held_karp(dist)
+    } else {
+        let order = nearest_neighbor(dist);
+        two_opt(dist, order)
+    }
+}
+
+pub fn route_length(dist: &[Vec<u64>], order: &[usize]) -> u64 {
+    order.windows(2).map(|e| dist[e[0]][e[1]]).sum()
+}
+
+// Exact dynamic programming solution, best[mask][i] is the shortest route from the start
+// over every waypoint in mask that ends at waypoint i
+pub fn held_karp(dist: &[Vec<u64>]) -> Vec<usize> {
+    let n = dist.len();
+    if n <= 2 {
+        return (0..n).collect();
+    }
+
+    let end = n - 1;
+    let count = n - 2;
+    let full = (1 << count) - 1;
+
+    let mut best = vec![vec![u64::MAX; count]; 1 << count];
+    let mut parent = vec![vec![usize::MAX; count]; 1 << count];
+    for i in 0..count {
+        best[1 << i][i] = dist[0][i + 1];
+    }
+
+    for mask in 1..=full {
+        for last in 0..count {
+            let curr = best[mask][last];
+            if mask & (1 << last) == 0 || curr == u64::MAX {
+                continue;
+            }
+
+            for next in 0..count {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+
+                let next_mask = mask | (1 << next);
+                let cost = curr.saturating_add(dist[last + 1][next + 1]);
+                if cost < best[next_mask][next] {
+                    best[next_mask][next] = cost;
+                    parent[next_mask][next] = last;
+                }
+            }
+        }
+    }
+
+    let mut last = (0..count)
+        .min_by_key(|i| best[full][*i].saturating_add(dist[*i + 1][end]))
+        .unwrap();
+
+    let mut order = vec![end];
+    let mut mask = full;
+    while last != usize::MAX {
+        order.push(last + 1);
+
+        let prev = parent[mask][last];
+        mask &= !(1 << last);
+        last = prev;
+    }
+
+    order.push(0);
+    order.reverse();
+    order
+}
+
+// Greedy route that always walks to the closest waypoint that wasn't visited yet
+pub fn nearest_neighbor(dist: &[Vec<u64>]) -> Vec<usize> {
+    let n = dist.len();
+    let end = n - 1;
+
+    let mut visited = vec![false; n];
+    let mut order = vec![0];
+    visited[0] = true;
+    visited[end] = true;
+
+    let mut curr = 0;
+    while order.len() < n - 1 {
+        let next = (0..n)
+            .filter(|i| !visited[*i])
+            .min_by_key(|i| dist[curr][*i])
+            .unwrap();
+
+        visited[next] = true;
+        order.push(next);
+        curr = next;
+    }
+
+    order.push(end);
+    order
+}
+
+// Reverses parts of the route as long as that makes it shorter. Start and end stay in place.
+pub fn two_opt(dist: &[Vec<u64>], mut order: Vec<usize>) -> Vec<usize> {
+    let n = order.len();
+    // Without at least two waypoints there is nothing that could be reversed
+    if n < 4 {
+        return order;
+    }
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 1..n - 2 {
+            for j in i + 1..n - 1 {
+                let (a, b) = (order[i - 1], order[i]);
+                let (c, d) = (order[j], order[j + 1]);
+
+                let before = dist[a][b] + dist[c][d];
+                let after = dist[a][c] + dist[b][d];
+                if after < before {
+                    order[i..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+
+    order
+}
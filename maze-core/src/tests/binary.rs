@@ -0,0 +1,97 @@
+use crate::{
+    generators::{decimate::decimate_maze, generate::{generate, Generator}},
+    point::{point::Point, size::Size},
+    solve::placement::Placement,
+    tools::{
+        binary::{binary_to_maze, maze_to_binary, pack_bits, unpack_bits, BinaryHeader, HEADER_LEN, VERSION},
+        consts::MazeOptions,
+        options::{AnimOptions, MazeData},
+    },
+};
+
+#[test]
+pub fn round_trip() {
+    let size = Size::square(41);
+    let opt = MazeOptions::rect(size.width, size.height, 17, 30);
+    let data = MazeData::headless(&opt, &AnimOptions::new(false, false, 5000.0));
+    let mut maze = generate(&data).unwrap();
+    decimate_maze(&data, &mut maze).unwrap();
+
+    let (start, end) = (Point { x: 1, y: 1 }, Point { x: size.width - 2, y: size.height - 2 });
+    let markers = Some((start, end));
+    for compressed in [false, true] {
+        let header = BinaryHeader::from_options(&opt, markers, compressed);
+        let bytes = maze_to_binary(&maze, &header).unwrap();
+
+        let (loaded, loaded_opt, loaded_header) = binary_to_maze(&bytes, false).unwrap();
+        assert_eq!(loaded, maze);
+        assert_eq!(loaded_header, header);
+        assert_eq!(loaded_header.version, VERSION);
+        assert_eq!(loaded_opt.size, size);
+        assert_eq!(loaded_opt.seed, 17);
+        assert_eq!(loaded_opt.decimate, 30);
+        assert_eq!(loaded_opt.generator, Some(Generator::HuntAndKill));
+        assert_eq!(loaded_opt.placement, Placement::Custom(start, end));
+
+        // One bit per cell instead of four bytes per pixel
+        if !compressed {
+            assert_eq!(bytes.len(), 44 + (size.len()).div_ceil(8));
+        }
+    }
+}
+
+#[test]
+pub fn bit_packing() {
+    let data = MazeData::headless(&MazeOptions::new(9, 2, 0), &AnimOptions::new(false, false, 5000.0));
+    let maze = generate(&data).unwrap();
+
+    let packed = pack_bits(&maze);
+    assert_eq!(packed.len(), 11);
+    assert_eq!(unpack_bits(&packed, maze.get_size()).unwrap(), maze);
+    assert!(unpack_bits(&packed, Size::new(9, 11)).is_err());
+}
+
+#[test]
+pub fn invalid_files() {
+    let opt = MazeOptions::new(11, 5, 0);
+    let data = MazeData::headless(&opt, &AnimOptions::new(false, false, 5000.0));
+    let maze = generate(&data).unwrap();
+    let bytes = maze_to_binary(&maze, &BinaryHeader::from_options(&opt, None, true)).unwrap();
+
+    let (_, loaded_opt, _) = binary_to_maze(&bytes, true).unwrap();
+    assert_eq!(loaded_opt.placement, Placement::Corners);
+
+    let mut wrong_magic = bytes.clone();
+    wrong_magic[0] = b'X';
+    assert!(binary_to_maze(&wrong_magic, false).is_err());
+
+    let mut wrong_version = bytes.clone();
+    wrong_version[4] = VERSION + 1;
+    assert!(binary_to_maze(&wrong_version, false).is_err());
+
+    assert!(binary_to_maze(&bytes[..bytes.len() - 1], false).is_err());
+
+    // Payload of a larger maze behind a header that claims a smaller one
+    let large_opt = MazeOptions::new(101, 5, 0);
+    let large = generate(&MazeData::headless(&large_opt, &AnimOptions::new(false, false, 5000.0))).unwrap();
+    let mut oversized = maze_to_binary(&large, &BinaryHeader::from_options(&large_opt, None, true)).unwrap();
+    oversized[6..14].copy_from_slice(&bytes[6..14]);
+    let err = binary_to_maze(&oversized, false).unwrap_err();
+    assert!(err.to_string().contains("decompresses to more"));
+
+    // Sizes without a single cell are rejected before the markers are looked at
+    let markers = Some((Point { x: 1, y: 1 }, Point { x: 9, y: 9 }));
+    let with_markers = maze_to_binary(&maze, &BinaryHeader::from_options(&opt, markers, false)).unwrap();
+    for size in [0u32, 1] {
+        let payload_len = size.div_ceil(8);
+        let mut tiny = with_markers[..HEADER_LEN].to_vec();
+        tiny[6..10].copy_from_slice(&size.to_le_bytes());
+        tiny[10..14].copy_from_slice(&size.to_le_bytes());
+        tiny[40..44].copy_from_slice(&payload_len.to_le_bytes());
+        tiny.resize(HEADER_LEN + payload_len as usize, 0);
+        assert!(binary_to_maze(&tiny, false).is_err());
+    }
+
+    let wrong_size = MazeOptions::rect(11, 9, 5, 0);
+    assert!(maze_to_binary(&maze, &BinaryHeader::from_options(&wrong_size, None, false)).is_err());
+}
@@ -0,0 +1,110 @@
+use crate::{
+    analysis::validate::validate,
+    chunks::{generate_tile, ChunkOptions, ChunkedMaze, TilePos},
+    point::{point::Point, size::Size},
+};
+
+#[test]
+pub fn tiles_are_reproducible() {
+    let options = ChunkOptions::default();
+    let tile = generate_tile(9, &options, TilePos::new(-3, 7)).unwrap();
+    assert_eq!(tile, generate_tile(9, &options, TilePos::new(-3, 7)).unwrap());
+    assert_ne!(tile, generate_tile(9, &options, TilePos::new(7, -3)).unwrap());
+    assert_ne!(tile, generate_tile(10, &options, TilePos::new(-3, 7)).unwrap());
+
+    // Visiting tiles in a different order doesn't change them
+    let mut world = ChunkedMaze::new(9, options).unwrap();
+    world.get_tile(TilePos::new(0, 0)).unwrap();
+    assert_eq!(world.get_tile(TilePos::new(-3, 7)).unwrap(), &tile);
+
+    assert!(ChunkedMaze::new(9, ChunkOptions { tile_size: 30, ..Default::default() }).is_err());
+    assert!(ChunkedMaze::new(9, ChunkOptions { doors: 0, ..Default::default() }).is_err());
+}
+
+#[test]
+pub fn neighbours_share_borders() {
+    let options = ChunkOptions { tile_size: 21, doors: 2, decimate: 0 };
+    let last = options.tile_size - 1;
+    let pos = TilePos::new(-1, 4);
+    let tile = generate_tile(3, &options, pos).unwrap();
+    let right = generate_tile(3, &options, pos.offset(1, 0)).unwrap();
+    let below = generate_tile(3, &options, pos.offset(0, 1)).unwrap();
+
+    for i in 0..options.tile_size {
+        assert_eq!(tile[Point { x: last, y: i }], right[Point { x: 0, y: i }]);
+        assert_eq!(tile[Point { x: i, y: last }], below[Point { x: i, y: 0 }]);
+    }
+
+    let world = ChunkedMaze::new(3, options).unwrap();
+    let (origin_x, origin_y) = world.tile_origin(pos);
+    assert_eq!(world.locate(origin_x - 1, origin_y), (pos.offset(-1, 0), Point { x: last - 1, y: 0 }));
+    assert_eq!(world.locate(origin_x, origin_y + 3), (pos, Point { x: 0, y: 3 }));
+}
+
+#[test]
+pub fn tiles_connect() {
+    let options = ChunkOptions { tile_size: 15, doors: 1, decimate: 0 };
+    let period = options.get_period();
+    let mut world = ChunkedMaze::new(12, options).unwrap();
+
+    // 3x3 tiles, the outer border still has the doors to the tiles around them
+    let region = world.region(-(period as i64), -(period as i64), Size::square(period * 3 + 1)).unwrap();
+    assert_eq!(world.loaded_tiles(), 16);
+
+    let validation = validate(&region).unwrap();
+    assert!(validation.is_connected());
+    assert!(validation.even_passages.is_empty());
+    assert_eq!(validation.border_breaks.len(), 12);
+    // Every tile is perfect, so only the 12 inner borders between 9 tiles create loops
+    assert_eq!(validation.loops, 4);
+
+    world.retain(|pos| pos.x == 0);
+    assert_eq!(world.loaded_tiles(), 4);
+}
+
+#[test]
+pub fn least_recently_used_tiles_are_dropped() {
+    let options = ChunkOptions { tile_size: 11, doors: 1, decimate: 0 };
+    let period = options.get_period() as i64;
+    let mut world = ChunkedMaze::new(5, options).unwrap();
+    world.set_capacity(3);
+
+    for x in 0..3 {
+        world.get_tile(TilePos::new(x, 0)).unwrap();
+    }
+    // Looking at the first tile again makes the second one the oldest
+    world.get_tile(TilePos::new(0, 0)).unwrap();
+    world.get_tile(TilePos::new(3, 0)).unwrap();
+    assert_eq!(world.loaded_tiles(), 3);
+    assert!(world.is_loaded(TilePos::new(0, 0)));
+    assert!(!world.is_loaded(TilePos::new(1, 0)));
+
+    // Tiles that aren't loaded are reported instead of generated
+    let size = Size::new(period as usize * 2, 5);
+    let (region, missing) = world.loaded_region(period / 2, 0, size);
+    assert_eq!(missing, vec![TilePos::new(1, 0)]);
+    assert_eq!(world.loaded_tiles(), 3);
+
+    world.insert_tile(TilePos::new(1, 0), generate_tile(5, &options, TilePos::new(1, 0)).unwrap());
+    let (loaded, missing) = world.loaded_region(period / 2, 0, size);
+    assert!(missing.is_empty());
+    assert_ne!(region, loaded);
+
+    world.set_capacity(16);
+    assert_eq!(world.region(period / 2, 0, size).unwrap(), loaded);
+}
+
+#[test]
+pub fn regions_larger_than_the_capacity() {
+    let options = ChunkOptions { tile_size: 11, doors: 1, decimate: 0 };
+    let period = options.get_period() as i64;
+    let size = Size::new(period as usize * 2, 5);
+
+    let mut world = ChunkedMaze::new(5, options).unwrap();
+    let expected = world.region(period / 2, 0, size).unwrap();
+
+    let mut small = ChunkedMaze::new(5, options).unwrap();
+    small.set_capacity(1);
+    assert_eq!(small.region(period / 2, 0, size).unwrap(), expected);
+    assert_eq!(small.loaded_tiles(), 1);
+}
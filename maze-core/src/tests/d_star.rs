@@ -0,0 +1,59 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    generators::{decimate::decimate_maze, generate::generate},
+    point::{point::Point, size::Size},
+    solve::{bfs::bfs_distances, d_star::DStarLite, solve::{solve, SolveAlgorithm, SolveOptions}},
+    tools::{consts::MazeOptions, options::{AnimOptions, MazeData}},
+};
+
+#[test]
+pub fn replans_after_toggles() {
+    let size = Size::square(31);
+    let data = MazeData::headless(
+        &MazeOptions::rect(size.width, size.height, 3, 60),
+        &AnimOptions::new(false, false, 5000.0)
+    );
+    let mut maze = generate(&data).unwrap();
+    decimate_maze(&data, &mut maze).unwrap();
+
+    let start = Point { x: 1, y: 1 };
+    let goal = Point { x: size.width - 2, y: size.height - 2 };
+    let mut planner = DStarLite::new(&maze, &start, &goal).unwrap();
+    planner.compute_shortest_path(&maze).unwrap();
+
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..200 {
+        let expected = bfs_distances(&maze, &goal).unwrap()[&planner.get_start()];
+        assert_eq!(planner.get_distance(), expected);
+
+        if let Some(next) = planner.next_step(&maze).unwrap() {
+            if rng.gen_bool(0.5) {
+                planner.move_to(&next);
+            }
+        }
+
+        let p = Point { x: rng.gen_range(1..size.width - 1), y: rng.gen_range(1..size.height - 1) };
+        if planner.toggle_wall(&mut maze, &p).is_ok() {
+            planner.compute_shortest_path(&maze).unwrap();
+        }
+    }
+}
+
+#[test]
+pub fn agent_reaches_goal() {
+    let size = Size::square(41);
+    let data = MazeData::headless(
+        &MazeOptions::rect(size.width, size.height, 11, 0),
+        &AnimOptions::new(false, false, 5000.0)
+    );
+    let mut maze = generate(&data).unwrap();
+
+    let mut options = SolveOptions::new(size);
+    options.algorithm = SolveAlgorithm::DStarLite;
+
+    let expected = bfs_distances(&maze, &options.start).unwrap()[&options.end].unwrap();
+    let (legs, _) = solve(&mut maze, &data, &options).unwrap();
+    assert_eq!(legs[0].len() as u64, expected + 1);
+    assert_eq!(legs[0].last(), Some(&options.end));
+}
@@ -0,0 +1,102 @@
+use std::sync::{Arc, RwLock};
+
+use anyhow::Result;
+
+use crate::{
+    events::{canvas::Canvas, event::{MazeEvent, MazeObserver}},
+    generators::generate::generate,
+    run::MazeRun,
+    solve::solve::SolveAlgorithm,
+    point::size::Size,
+    tools::{consts::MazeOptions, options::{AnimOptions, MazeData}},
+};
+
+#[derive(Default)]
+struct Recorder {
+    events: RwLock<Vec<MazeEvent>>,
+}
+
+impl MazeObserver for Recorder {
+    fn on_event(&self, event: &MazeEvent) -> Result<()> {
+        self.events.write().unwrap().push(event.clone());
+        Ok(())
+    }
+}
+
+fn recorded(size: Size, seed: u64, decimate: usize) -> (Arc<Recorder>, MazeData) {
+    let recorder = Arc::new(Recorder::default());
+    let mut data = MazeData::headless(
+        &MazeOptions::rect(size.width, size.height, seed, decimate),
+        &AnimOptions::new(false, true, 5000.0)
+    );
+    data.add_observer(recorder.clone());
+
+    (recorder, data)
+}
+
+#[test]
+pub fn replay_generation() {
+    let (recorder, data) = recorded(Size::square(31), 7, 0);
+    let maze = generate(&data).unwrap();
+
+    let events = recorder.events.read().unwrap();
+    assert_eq!(events.first(), Some(&MazeEvent::Started { size: Size::square(31) }));
+
+    let canvas = Canvas::default();
+    for event in events.iter() {
+        canvas.apply(event);
+    }
+    assert_eq!(canvas.get_maze(), maze);
+
+    let progress = events
+        .iter()
+        .filter_map(|e| match e { MazeEvent::Progress(p) => Some(*p), _ => None })
+        .collect::<Vec<_>>();
+    assert!(progress.windows(2).all(|e| e[0] <= e[1]));
+    assert_eq!(progress.last(), Some(&1.0));
+}
+
+#[test]
+pub fn paths_are_reported() {
+    let (recorder, data) = recorded(Size::new(21, 15), 3, 20);
+    let run = MazeRun::generate(&data, SolveAlgorithm::AStar).unwrap();
+
+    let events = recorder.events.read().unwrap();
+    let paths = events
+        .iter()
+        .filter_map(|e| match e { MazeEvent::PathFound(p) => Some(p.clone()), _ => None })
+        .collect::<Vec<_>>();
+    assert_eq!(paths, run.legs);
+    assert_eq!(events.last(), Some(&MazeEvent::Finished));
+
+    // Status text goes to the observers instead of stdout
+    let messages = events
+        .iter()
+        .filter_map(|e| match e { MazeEvent::Message(text) => Some(text.as_str()), _ => None })
+        .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["Decimating...", "Running a*"]);
+
+    // Decimation happens after generation, so the canvas only matches if it was reported as well
+    let canvas = Canvas::default();
+    for event in events.iter() {
+        canvas.apply(event);
+    }
+    assert_eq!(canvas.get_maze(), run.maze);
+}
+
+#[test]
+pub fn observer_can_abort() {
+    struct Abort;
+    impl MazeObserver for Abort {
+        fn on_event(&self, _event: &MazeEvent) -> Result<()> {
+            anyhow::bail!("Aborted.")
+        }
+    }
+
+    let mut data = MazeData::headless(&MazeOptions::new(21, 1, 0), &AnimOptions::default());
+    data.add_observer(Arc::new(Abort));
+    assert!(generate(&data).is_err());
+
+    let headless = MazeData::headless(&MazeOptions::new(21, 1, 0), &AnimOptions::default());
+    assert!(generate(&headless).is_ok());
+}
@@ -0,0 +1,102 @@
+use std::time::Instant;
+
+use crate::{
+    analysis::validate::validate,
+    generators::generate::{generate, generate_as, Generator},
+    tools::{
+        consts::{default_threads, BitMaze, MazeOptions},
+        options::{AnimOptions, MazeData},
+        storage::MazeStorage,
+        text::{maze_to_text, TextStyle},
+    },
+};
+
+// Hunt and kill output for seed 42, changes to the generator have to keep producing this exact maze
+const HUNT_AND_KILL_42: &str = "\
+#####################
+#     #   #   # #   #
+# ### ### # # # # # #
+#   #     # # #   # #
+### ######### ##### #
+# #     #   #     # #
+# ##### # # ##### # #
+# #   #   # #     # #
+# # # ### # # ##### #
+#   #     #         #
+#####################
+";
+
+
+// Takes minutes in debug builds, run with `cargo test --release -- --ignored bench_large`
+#[test]
+#[ignore]
+pub fn bench_large() {
+    let seed = rand::random();
+    for (generator, threads) in [(Generator::HuntAndKill, 1), (Generator::HuntAndKillScan, 1), (Generator::Parallel, default_threads())] {
+        println!("Generating with {} on {} threads...", generator, threads);
+        let mut opt = MazeOptions::new(10000, seed, 30);
+        opt.generator = Some(generator);
+        opt.threads = threads;
+
+        let data = MazeData::headless(
+            &opt,
+            &AnimOptions::new(false, false, 5000.0)
+        );
+        let start = Instant::now();
+        let _e = generate(&data).unwrap();
+        println!("Done after {:?}.", start.elapsed());
+    }
+}
+
+#[test]
+pub fn parallel_small_with_many_threads() {
+    for (width, height) in [(5, 5), (11, 11), (21, 21), (21, 5), (7, 31)] {
+        let mut opt = MazeOptions::rect(width, height, 1, 0);
+        opt.generator = Some(Generator::Parallel);
+        opt.threads = 8;
+
+        let maze = generate(&MazeData::headless(&opt, &AnimOptions::new(false, false, 1.0))).unwrap();
+        assert!(validate(&maze).unwrap().perfect, "{}x{}", width, height);
+    }
+}
+
+#[test]
+pub fn hunt_and_kill_is_stable() {
+    let data = MazeData::headless(&MazeOptions::rect(21, 11, 42, 0), &AnimOptions::new(false, false, 1.0));
+    let maze = generate(&data).unwrap();
+    assert_eq!(maze_to_text(&maze, &[], None, TextStyle::Ascii).unwrap(), HUNT_AND_KILL_42);
+}
+
+#[test]
+pub fn hunt_and_kill_scan_is_perfect() {
+    for seed in 0..5 {
+        let mut opt = MazeOptions::rect(41, 31, seed, 0);
+        opt.generator = Some(Generator::HuntAndKillScan);
+        opt.validate = true;
+
+        let headless = || MazeData::headless(&opt, &AnimOptions::new(false, false, 1.0));
+        let maze = generate(&headless()).unwrap();
+        assert_eq!(generate_as::<BitMaze>(&headless()).unwrap().to_maze(), maze);
+        assert_eq!(maze, generate(&headless()).unwrap());
+    }
+}
+
+#[test]
+pub fn parallel_is_reproducible() {
+    let generate_with = |seed: u64, threads: usize| {
+        let mut opt = MazeOptions::rect(201, 151, seed, 0);
+        opt.generator = Some(Generator::Parallel);
+        opt.threads = threads;
+        opt.validate = true;
+
+        generate(&MazeData::headless(&opt, &AnimOptions::new(false, false, 1.0))).unwrap()
+    };
+
+    let maze = generate_with(8, 3);
+    assert_eq!(maze, generate_with(8, 3));
+    assert_ne!(maze, generate_with(9, 3));
+    assert!(validate(&generate_with(8, 5)).unwrap().perfect);
+
+    // More threads than cells in a row
+    assert!(validate(&generate_with(2, 64)).unwrap().perfect);
+}
@@ -0,0 +1,124 @@
+use crate::{
+    generators::{decimate::decimate_maze, generate::generate},
+    graph::graph::{MazeGraph, NodeKind},
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    solve::bfs::bfs_distances,
+    tools::{consts::{Maze, MazeOptions}, matrix::get_connected_cells, options::{AnimOptions, MazeData}},
+};
+
+fn generate_maze(size: Size, seed: u64, decimate: usize) -> Maze {
+    let data = MazeData::headless(
+        &MazeOptions::rect(size.width, size.height, seed, decimate),
+        &AnimOptions::new(false, false, 5000.0)
+    );
+    let mut maze = generate(&data).unwrap();
+    decimate_maze(&data, &mut maze).unwrap();
+
+    maze
+}
+
+#[test]
+pub fn perfect_maze_graph_is_tree() {
+    let size = Size::square(41);
+    for seed in 0..5 {
+        let maze = generate_maze(size, seed, 0);
+        let graph = MazeGraph::from_maze(&maze, &[]).unwrap();
+
+        let nodes = graph.get_nodes().len();
+        assert_eq!(graph.get_corridors().len(), nodes - 1);
+
+        // The corridors cover every passage of the spanning tree exactly once
+        let total: u64 = graph.get_corridors().iter().map(|e| e.get_weight()).sum();
+        assert_eq!(total as usize, 20 * 20 - 1);
+
+        for i in 0..nodes {
+            let degree = graph.get_edges(i).len();
+            match graph.get_kind(i) {
+                NodeKind::DeadEnd => assert_eq!(degree, 1),
+                NodeKind::Junction => assert!(degree >= 3),
+                kind => panic!("Unexpected node kind {:?}", kind),
+            }
+        }
+    }
+}
+
+// Passages between two cells, every one of them has to be part of exactly one corridor
+fn count_passages(maze: &Maze) -> u64 {
+    let size = maze.get_size();
+    let mut count = 0;
+    for y in (1..size.height).step_by(2) {
+        for x in (1..size.width).step_by(2) {
+            count += get_connected_cells(maze, &Point { x, y }).unwrap().len() as u64;
+        }
+    }
+
+    count / 2
+}
+
+#[test]
+pub fn decimated_graph_covers_every_passage() {
+    for seed in 0..5 {
+        let maze = generate_maze(Size::square(41), seed, 80);
+        let graph = MazeGraph::from_maze(&maze, &[]).unwrap();
+        let total: u64 = graph.get_corridors().iter().map(|e| e.get_weight()).sum();
+        assert_eq!(total, count_passages(&maze), "seed {}", seed);
+    }
+
+    // Only corridors, without a node for it the whole loop would be missing
+    let mut ring = Grid::new(Size::square(7), PointState::Wall);
+    for i in 1..6 {
+        for p in [Point { x: i, y: 1 }, Point { x: i, y: 5 }, Point { x: 1, y: i }, Point { x: 5, y: i }] {
+            ring[p] = PointState::Passage;
+        }
+    }
+    let graph = MazeGraph::from_maze(&ring, &[]).unwrap();
+    assert_eq!(graph.get_nodes().len(), 1);
+    assert_eq!(graph.get_kind(0), NodeKind::Loop);
+    assert_eq!(graph.get_corridors().len(), 1);
+    assert_eq!(graph.get_corridors()[0].get_weight(), count_passages(&ring));
+    assert_eq!(count_passages(&ring), 8);
+}
+
+#[test]
+pub fn graph_shortest_path_matches_bfs() {
+    let size = Size::square(51);
+    let start = Point { x: 1, y: 1 };
+    let end = Point { x: size.width - 2, y: size.height - 2 };
+    for seed in 0..5 {
+        let maze = generate_maze(size, seed, 60);
+        let graph = MazeGraph::from_maze(&maze, &[start, end]).unwrap();
+
+        let (length, path) = graph.shortest_path(&start, &end).unwrap().unwrap();
+        let expected = bfs_distances(&maze, &start).unwrap()[&end].unwrap();
+        assert_eq!(length, expected);
+        assert_eq!(path.len() as u64, length + 1);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+
+        for pair in path.windows(2) {
+            assert!(get_connected_cells(&maze, &pair[0]).unwrap().contains(&pair[1]));
+        }
+    }
+}
+
+#[test]
+pub fn graph_export() {
+    let size = Size::square(21);
+    let maze = generate_maze(size, 3, 0);
+    let graph = MazeGraph::from_maze(&maze, &[Point { x: 1, y: 1 }]).unwrap();
+
+    let nodes = graph.get_nodes().len();
+    let corridors = graph.get_corridors().len();
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("graph maze {"));
+    assert_eq!(dot.matches(" -- ").count(), corridors);
+    assert_eq!(dot.matches("pos=").count(), nodes);
+
+    let graphml = graph.to_graphml();
+    assert_eq!(graphml.matches("<node ").count(), nodes);
+    assert_eq!(graphml.matches("<edge ").count(), corridors);
+    assert!(graphml.trim_end().ends_with("</graphml>"));
+
+    assert!(MazeGraph::from_maze(&maze, &[Point { x: 2, y: 2 }]).is_err());
+}
@@ -0,0 +1,37 @@
+use crate::point::{direction::Direction, grid::Grid, point::Point, size::Size};
+
+#[test]
+pub fn access_and_conversion() {
+    let size = Size::new(5, 3);
+    let mut grid = Grid::from_fn(size, |p| p.y * 10 + p.x);
+    assert_eq!(grid.len(), 15);
+    assert_eq!(grid[Point { x: 4, y: 2 }], 24);
+    assert_eq!(grid.get(&Point { x: 5, y: 0 }), None);
+    assert_eq!(grid.get(&Point { x: 0, y: 3 }), None);
+    assert!(grid.set(&Point { x: 5, y: 0 }, 0).is_err());
+
+    grid[Point { x: 1, y: 1 }] = 99;
+    assert_eq!(grid.get(&Point { x: 1, y: 1 }), Some(&99));
+
+    // Unchecked access doesn't care about the width, so the point wraps into the next row
+    assert_eq!(*grid.get_unchecked(&Point { x: 5, y: 0 }), 10);
+
+    for (i, p) in grid.points().enumerate() {
+        assert_eq!(grid.to_index(&p), i);
+        assert_eq!(grid.to_point(i), p);
+    }
+
+    assert_eq!(grid.rows().count(), 3);
+    assert!(Grid::from_vec(size, vec![0; 14]).is_err());
+}
+
+#[test]
+pub fn neighbours() {
+    let grid = Grid::new(Size::new(5, 3), ());
+    let corner = grid.neighbours(&Point { x: 0, y: 0 }).collect::<Vec<_>>();
+    assert_eq!(corner, vec![Point { x: 1, y: 0 }, Point { x: 0, y: 1 }]);
+    assert_eq!(grid.neighbours(&Point { x: 2, y: 1 }).count(), 4);
+
+    let cells = grid.cell_neighbours(&Point { x: 1, y: 1 }).collect::<Vec<_>>();
+    assert_eq!(cells, vec![(Direction::RIGHT, Point { x: 3, y: 1 })]);
+}
@@ -0,0 +1,78 @@
+use image::{DynamicImage, GrayImage, Luma, Rgb, RgbImage};
+
+use crate::{
+    point::{point::Point, point_state::PointState, size::Size},
+    run::MazeRun,
+    solve::{placement::Placement, solve::SolveAlgorithm},
+    tools::{
+        consts::MazeOptions,
+        image::{img_to_maze, pixels_to_img, ImportOptions},
+        options::{AnimOptions, MazeData},
+        render::render_maze,
+    },
+};
+
+fn solved_run() -> MazeRun {
+    let data = MazeData::headless(&MazeOptions::new(31, 4, 0), &AnimOptions::new(false, false, 5000.0));
+    MazeRun::generate(&data, SolveAlgorithm::AStar).unwrap()
+}
+
+#[test]
+pub fn scaled_with_markers() {
+    let run = solved_run();
+    let pixels = render_maze(&run.maze, &run.visual_overwrites);
+
+    for scale in [1, 3, 8] {
+        let img = DynamicImage::ImageRgba8(pixels_to_img(run.size, &pixels, scale));
+        let (maze, opt) = img_to_maze(&img, &ImportOptions::default()).unwrap();
+        assert_eq!(maze, run.maze);
+        assert_eq!(opt.size, run.size);
+        assert_eq!(opt.placement, Placement::Custom(run.options.start, run.options.end));
+
+        // Cells which do not divide the image evenly are rejected
+        if scale > 1 {
+            assert!(img_to_maze(&img, &ImportOptions { cell_size: Some(scale + 1), ..Default::default() }).is_err());
+        }
+    }
+}
+
+#[test]
+pub fn grayscale_threshold() {
+    let run = solved_run();
+    let scale = 2;
+    let (width, height) = ((run.size.width * scale) as u32, (run.size.height * scale) as u32);
+    let img = GrayImage::from_fn(width, height, |x, y| {
+        let state = run.maze[Point { x: x as usize / scale, y: y as usize / scale }];
+        if state == PointState::Wall { Luma([90]) } else { Luma([200]) }
+    });
+    let img = DynamicImage::ImageLuma8(img);
+
+    let (maze, opt) = img_to_maze(&img, &ImportOptions::default()).unwrap();
+    assert_eq!(maze, run.maze);
+    assert_eq!(opt.placement, MazeOptions::default().placement);
+
+    // Every pixel is a passage now, which leaves holes in the border
+    let threshold = ImportOptions { threshold: 80, cell_size: Some(scale), ..Default::default() };
+    assert!(img_to_maze(&img, &threshold).is_err());
+    let (maze, _) = img_to_maze(&img, &ImportOptions { validate: false, ..threshold }).unwrap();
+    assert!(maze.iter().all(|e| *e == PointState::Passage));
+}
+
+#[test]
+pub fn rectangular() {
+    let rows = ["#######", "# #   #", "# # # #", "#   # #", "#######"];
+    let img = RgbImage::from_fn(7 * 4, 5 * 4, |x, y| {
+        let c = rows[y as usize / 4].as_bytes()[x as usize / 4];
+        if c == b'#' { Rgb([0, 0, 0]) } else { Rgb([255, 255, 255]) }
+    });
+
+    let (maze, opt) = img_to_maze(&DynamicImage::ImageRgb8(img), &ImportOptions::default()).unwrap();
+    assert_eq!(opt.size, Size::new(7, 5));
+    assert_eq!(opt.placement, Placement::Corners);
+    assert_eq!(maze.len(), 35);
+    assert_eq!(maze[Point { x: 1, y: 1 }], PointState::Passage);
+    assert_eq!(maze[Point { x: 3, y: 3 }], PointState::Passage);
+
+    let even = RgbImage::from_pixel(6, 5, Rgb([0, 0, 0]));
+    assert!(img_to_maze(&DynamicImage::ImageRgb8(even), &ImportOptions::default()).is_err());
+}
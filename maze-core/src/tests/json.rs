@@ -0,0 +1,158 @@
+use std::{fs, sync::Arc};
+
+use serde_json::Value;
+
+use crate::{
+    events::canvas::Canvas,
+    generators::generate::Generator,
+    point::{point::Point, point_state::VisualIndicator},
+    run::{load_run, MazeRun},
+    solve::{placement::Placement, solve::SolveAlgorithm},
+    tools::{
+        consts::MazeOptions,
+        json::{MazeDocument, JSON_SCHEMA},
+        options::{AnimOptions, MazeData},
+        render::render_maze,
+    },
+};
+
+fn run(algorithm: SolveAlgorithm, placement: Placement) -> MazeRun {
+    let mut opt = MazeOptions::new(25, 6, 10);
+    opt.placement = placement;
+    opt.waypoints = 3;
+    opt.analyze = true;
+
+    let data = MazeData::headless(&opt, &AnimOptions::new(false, false, 5000.0));
+    MazeRun::generate(&data, algorithm).unwrap()
+}
+
+#[test]
+pub fn round_trip() {
+    for (algorithm, placement) in [
+        (SolveAlgorithm::AStar, Placement::Corners),
+        (SolveAlgorithm::Waypoints, Placement::Openings),
+        (SolveAlgorithm::None, Placement::FarthestApart),
+    ] {
+        let run = run(algorithm, placement);
+        let json = run.to_json().unwrap();
+        let loaded = MazeRun::from_json(&json).unwrap();
+
+        assert_eq!(loaded.maze, run.maze);
+        assert_eq!(loaded.legs, run.legs);
+        assert_eq!(loaded.metrics, run.metrics);
+        assert_eq!(loaded.options.start, run.options.start);
+        assert_eq!(loaded.options.waypoints, run.options.waypoints);
+        assert_eq!(loaded.maze_options.seed, 6);
+        assert_eq!(loaded.maze_options.placement, placement);
+        assert_eq!(loaded.maze_options.generator, Some(Generator::HuntAndKill));
+        assert_eq!(loaded.to_document(), run.to_document());
+
+        // The solution overlay is drawn again, only the cells the solver searched through are gone
+        for (p, restored) in loaded.visual_overwrites.iter() {
+            assert_eq!(Some(restored), run.visual_overwrites.get(&p));
+        }
+        let drawn = loaded.visual_overwrites.iter().filter(|(_, e)| **e == VisualIndicator::SolvePath).count();
+        assert_eq!(drawn > 0, !run.legs.is_empty());
+    }
+}
+
+#[test]
+pub fn restore_saved_run() {
+    let run = run(SolveAlgorithm::Waypoints, Placement::Corners);
+
+    let dir = std::env::temp_dir().join(format!("maze-restore-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("run.json").to_string_lossy().to_string();
+    run.save(&path).unwrap();
+    let restored = load_run(&path).unwrap().unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(restored.legs, run.legs);
+    assert_eq!(restored.options.algorithm, SolveAlgorithm::Waypoints);
+    assert_eq!(restored.options.waypoints, run.options.waypoints);
+    assert_eq!(restored.metrics, run.metrics);
+
+    // Observers get the stored solution, nothing is solved again
+    let canvas = Arc::new(Canvas::default());
+    let mut data = MazeData::headless(&MazeOptions::default(), &AnimOptions::new(false, false, 5000.0));
+    data.add_observer(canvas.clone());
+    restored.show(&data).unwrap();
+    assert!(canvas.is_finished());
+    assert_eq!(canvas.get_maze(), run.maze);
+    assert_eq!(canvas.render(), render_maze(&restored.maze, &restored.visual_overwrites));
+
+    assert!(load_run("maze.png").unwrap().is_none());
+}
+
+#[test]
+pub fn invalid_documents() {
+    let mut doc = run(SolveAlgorithm::AStar, Placement::Corners).to_document();
+    doc.version += 1;
+    assert!(MazeRun::from_document(&doc).is_err());
+
+    let mut doc = run(SolveAlgorithm::AStar, Placement::Corners).to_document();
+    doc.grid[0] = doc.grid[0].replacen('#', " ", 2);
+    assert!(MazeRun::from_document(&doc).is_err());
+
+    let mut doc = run(SolveAlgorithm::AStar, Placement::Corners).to_document();
+    doc.grid.pop();
+    assert!(MazeRun::from_document(&doc).is_err());
+
+    // Points outside of the grid, on walls or solutions that skip cells are rejected instead of drawn
+    let mut doc = run(SolveAlgorithm::Waypoints, Placement::Corners).to_document();
+    doc.waypoints[0].x = 999;
+    assert!(MazeRun::from_document(&doc).is_err());
+
+    let mut doc = run(SolveAlgorithm::AStar, Placement::Corners).to_document();
+    doc.end = Point { x: 0, y: 0 };
+    assert!(MazeRun::from_document(&doc).is_err());
+
+    let mut doc = run(SolveAlgorithm::AStar, Placement::Corners).to_document();
+    doc.solution[0].remove(1);
+    assert!(MazeRun::from_document(&doc).is_err());
+
+    let mut doc = run(SolveAlgorithm::AStar, Placement::Corners).to_document();
+    doc.solution[0][1].y += 100;
+    assert!(MazeRun::from_document(&doc).is_err());
+
+    // Metrics are recalculated if another tool left them out
+    let run = run(SolveAlgorithm::AStar, Placement::Corners);
+    let mut doc = run.to_document();
+    doc.metrics = None;
+    assert_eq!(MazeRun::from_document(&doc).unwrap().metrics, run.metrics);
+}
+
+#[test]
+pub fn matches_schema() {
+    let schema: Value = serde_json::from_str(JSON_SCHEMA).unwrap();
+    let doc: MazeDocument = run(SolveAlgorithm::AStar, Placement::Corners).to_document();
+    let value = serde_json::to_value(&doc).unwrap();
+
+    let keys = |e: &Value| e.as_object().unwrap().keys().cloned().collect::<Vec<_>>();
+    let mut written = keys(&value);
+    let mut described = keys(&schema["properties"]);
+    written.sort();
+    described.sort();
+    assert_eq!(written, described);
+
+    for required in schema["required"].as_array().unwrap() {
+        assert!(value.get(required.as_str().unwrap()).is_some());
+    }
+
+    let mut parameters = keys(&value["parameters"]);
+    let mut described = keys(&schema["properties"]["parameters"]["properties"]);
+    parameters.sort();
+    described.sort();
+    assert_eq!(parameters, described);
+
+    let mut metrics = keys(&value["metrics"]);
+    let mut described = keys(&schema["$defs"]["metrics"]["properties"]);
+    metrics.sort();
+    described.sort();
+    assert_eq!(metrics, described);
+
+    let algorithms = schema["properties"]["algorithm"]["enum"].as_array().unwrap();
+    for algorithm in SolveAlgorithm::all() {
+        assert!(algorithms.contains(&serde_json::to_value(algorithm).unwrap()));
+    }
+}
@@ -0,0 +1,38 @@
+use std::fs;
+
+use crate::{
+    run::{load_maze, MazeRun},
+    solve::{placement::Placement, solve::SolveAlgorithm},
+    tools::{consts::MazeOptions, options::{AnimOptions, MazeData}},
+};
+
+#[test]
+pub fn reopen_with_openings() {
+    let mut opt = MazeOptions::new(21, 4, 0);
+    opt.placement = Placement::Openings;
+    let data = MazeData::headless(&opt, &AnimOptions::new(false, false, 5000.0));
+    let run: MazeRun = MazeRun::generate(&data, SolveAlgorithm::AStar).unwrap();
+
+    let dir = std::env::temp_dir().join(format!("maze-load-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    for extension in ["png", "txt", "utf8", "maze", "json"] {
+        let path = dir.join(format!("openings.{}", extension)).to_string_lossy().to_string();
+        run.save(&path).unwrap();
+
+        let (maze, loaded) = load_maze(&path).unwrap_or_else(|e| panic!("{}: {}", extension, e));
+        assert_eq!(maze, run.maze, "{}", extension);
+        assert_eq!(loaded.placement, Placement::Openings, "{}", extension);
+
+        // Solving the loaded maze again carves the same openings
+        let data = MazeData::headless(&loaded, &AnimOptions::new(false, false, 5000.0));
+        let reloaded = MazeRun::load(&data, maze, SolveAlgorithm::AStar).unwrap();
+        assert_eq!(reloaded.maze, run.maze);
+        assert_eq!(reloaded.legs, run.legs);
+    }
+    fs::remove_dir_all(&dir).unwrap();
+
+    // Any other hole in the border is still rejected
+    let mut doc = run.to_document();
+    doc.grid[5].replace_range(0..1, " ");
+    assert!(MazeRun::from_document(&doc).is_err());
+}
@@ -0,0 +1,70 @@
+use crate::{
+    analysis::metrics::{analyze, MazeMetrics},
+    generators::{decimate::decimate_maze, generate::generate},
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    tools::{consts::MazeOptions, options::{AnimOptions, MazeData}},
+};
+
+#[test]
+pub fn perfect_maze_metrics() {
+    let size = Size::square(41);
+    let data = MazeData::headless(
+        &MazeOptions::rect(size.width, size.height, 5, 0),
+        &AnimOptions::new(false, false, 5000.0)
+    );
+    let maze = generate(&data).unwrap();
+
+    let start = Point { x: 1, y: 1 };
+    let end = Point { x: size.width - 2, y: size.height - 2 };
+    let metrics = analyze(&maze, &start, &end).unwrap();
+
+    assert_eq!(metrics.cells, 20 * 20);
+    assert_eq!(metrics.components, 1);
+    assert_eq!(metrics.loops, 0);
+    assert_eq!(metrics.degree_counts.iter().sum::<usize>(), metrics.cells);
+    assert_eq!(metrics.degree_counts[0], 0);
+    assert!(metrics.dead_ends > 0);
+    assert!(metrics.tortuosity >= 1.0);
+
+    let corridor_cells: usize = metrics.corridor_lengths.iter().map(|(length, count)| length * count).sum();
+    assert_eq!(corridor_cells, metrics.degree_counts[2]);
+    let corridors: usize = metrics.corridor_lengths.values().sum();
+    assert_eq!(metrics.river_factor, corridor_cells as f64 / corridors as f64);
+
+    let json = metrics.to_json().unwrap();
+    let parsed: MazeMetrics = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, metrics);
+}
+
+#[test]
+pub fn braided_maze_has_loops() {
+    let size = Size::square(41);
+    let data = MazeData::headless(
+        &MazeOptions::rect(size.width, size.height, 5, 100),
+        &AnimOptions::new(false, false, 5000.0)
+    );
+    let mut maze = generate(&data).unwrap();
+    decimate_maze(&data, &mut maze).unwrap();
+
+    let metrics = analyze(&maze, &Point { x: 1, y: 1 }, &Point { x: 39, y: 39 }).unwrap();
+    assert!(metrics.loops > 0);
+    assert_eq!(metrics.components, 1);
+}
+
+#[test]
+pub fn hand_made_metrics() {
+    // Two separate corridors of two cells each, one of them bent into an L
+    let size = Size::square(7);
+    let mut maze = Grid::new(size, PointState::Wall);
+    for p in [(1, 1), (2, 1), (3, 1), (5, 1), (5, 2), (5, 3), (4, 3), (3, 3)] {
+        maze[Point { x: p.0, y: p.1 }] = PointState::Passage;
+    }
+
+    let metrics = analyze(&maze, &Point { x: 1, y: 1 }, &Point { x: 3, y: 3 }).unwrap();
+    assert_eq!(metrics.cells, 5);
+    assert_eq!(metrics.components, 2);
+    assert_eq!(metrics.dead_ends, 4);
+    assert_eq!(metrics.degree_counts[2], 1);
+    assert_eq!(metrics.river_factor, 1.0);
+    assert_eq!(metrics.solution_length, None);
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+pub mod binary;
+#[cfg(test)]
+pub mod chunks;
+#[cfg(test)]
+pub mod d_star;
+#[cfg(test)]
+pub mod events;
+#[cfg(test)]
+pub mod generate;
+#[cfg(test)]
+pub mod graph;
+#[cfg(test)]
+pub mod grid;
+#[cfg(test)]
+pub mod image;
+#[cfg(test)]
+pub mod json;
+#[cfg(test)]
+pub mod load;
+#[cfg(test)]
+pub mod metrics;
+#[cfg(test)]
+pub mod multi_agent;
+#[cfg(test)]
+pub mod pdf;
+#[cfg(test)]
+pub mod placement;
+#[cfg(test)]
+pub mod recorder;
+#[cfg(test)]
+pub mod shortest_paths;
+#[cfg(test)]
+pub mod solve;
+#[cfg(test)]
+pub mod storage;
+#[cfg(test)]
+pub mod svg;
+#[cfg(test)]
+pub mod waypoints;
+#[cfg(test)]
+pub mod text;
+#[cfg(test)]
+pub mod validate;
\ No newline at end of file
@@ -0,0 +1,96 @@
+use crate::{
+    generators::{decimate::decimate_maze, generate::generate},
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    solve::{multi_agent::{plan_agents, Agent}, placement::random_cells},
+    tools::{consts::MazeOptions, matrix::get_connected_cells, options::{AnimOptions, MazeData}},
+};
+
+#[test]
+pub fn agents_never_collide() {
+    let mut solved = 0;
+    for seed in 0..5 {
+        let size = Size::square(21);
+        let data = MazeData::headless(
+            &MazeOptions::rect(size.width, size.height, seed, 80),
+            &AnimOptions::new(false, false, 5000.0)
+        );
+        let mut maze = generate(&data).unwrap();
+        decimate_maze(&data, &mut maze).unwrap();
+
+        let cells = random_cells(&maze, seed, 6, &[]).unwrap();
+        let agents: Vec<Agent> = cells.chunks(2).map(|e| Agent { start: e[0], end: e[1] }).collect();
+
+        let paths = match plan_agents(&maze, &agents) {
+            Ok(paths) => paths,
+            // Prioritized planning is not complete, some layouts just can't be solved with it
+            Err(_) => continue,
+        };
+        solved += 1;
+
+        for (agent, path) in agents.iter().zip(paths.iter()) {
+            assert_eq!(path.first(), Some(&agent.start));
+            assert_eq!(path.last(), Some(&agent.end));
+
+            for step in path.windows(2) {
+                let moves = get_connected_cells(&maze, &step[0]).unwrap();
+                assert!(step[0] == step[1] || moves.contains(&step[1]));
+            }
+        }
+
+        let steps = paths[0].len();
+        assert!(paths.iter().all(|e| e.len() == steps));
+        for t in 0..steps {
+            let positions: Vec<Point> = paths.iter().map(|e| e[t]).collect();
+            for (i, p) in positions.iter().enumerate() {
+                assert!(!positions[..i].contains(p), "Vertex conflict at {} on step {}", p, t);
+            }
+
+            if t == 0 {
+                continue;
+            }
+
+            for a in 0..paths.len() {
+                for b in 0..paths.len() {
+                    let swapped = paths[a][t - 1] == paths[b][t] && paths[b][t - 1] == paths[a][t];
+                    assert!(a == b || !swapped, "Edge conflict on step {}", t);
+                }
+            }
+        }
+    }
+
+    assert!(solved > 0);
+}
+
+#[test]
+pub fn shared_goal_errors() {
+    let size = Size::square(11);
+    let maze = Grid::new(size, PointState::Passage);
+    let agents = [
+        Agent { start: Point { x: 1, y: 1 }, end: Point { x: 9, y: 9 } },
+        Agent { start: Point { x: 3, y: 1 }, end: Point { x: 9, y: 9 } },
+    ];
+
+    assert!(plan_agents(&maze, &agents).is_err());
+}
+
+#[test]
+pub fn blocked_goal_errors() {
+    // Single corridor, the first agent parks right in front of the goal of the second one
+    let mut maze = Grid::new(Size::square(41), PointState::Wall);
+    for x in 1..40 {
+        maze[Point { x, y: 1 }] = PointState::Passage;
+    }
+
+    let agents = [
+        Agent { start: Point { x: 21, y: 1 }, end: Point { x: 37, y: 1 } },
+        Agent { start: Point { x: 1, y: 1 }, end: Point { x: 39, y: 1 } },
+    ];
+    let err = plan_agents(&maze, &agents).unwrap_err();
+    assert!(err.to_string().contains("Gave up"), "{}", err);
+
+    // Walled off goals are found without searching through time at all
+    maze[Point { x: 38, y: 1 }] = PointState::Wall;
+    let agents = [Agent { start: Point { x: 1, y: 1 }, end: Point { x: 39, y: 1 } }];
+    let err = plan_agents(&maze, &agents).unwrap_err();
+    assert!(err.to_string().contains("can not be reached"), "{}", err);
+}
@@ -0,0 +1,56 @@
+use crate::{
+    export::{pdf::{mazes_to_pdf, PageSize, PdfOptions}, segments::WallStyle},
+    run::MazeRun,
+    solve::solve::SolveAlgorithm,
+    tools::{consts::MazeOptions, options::{AnimOptions, MazeData}},
+};
+
+fn runs(count: u64) -> Vec<MazeRun> {
+    (0..count)
+        .map(|seed| {
+            let data = MazeData::headless(&MazeOptions::new(21, seed, 0), &AnimOptions::new(false, false, 5000.0));
+            MazeRun::generate(&data, SolveAlgorithm::AStar).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+pub fn puzzle_book() {
+    let runs = runs(3);
+    let pdf = mazes_to_pdf(&runs, &PdfOptions::default()).unwrap();
+    let text = String::from_utf8_lossy(&pdf);
+
+    assert!(text.starts_with("%PDF-1.4"));
+    assert!(text.trim_end().ends_with("%%EOF"));
+    assert!(text.contains("/Count 6"));
+    assert!(text.contains("(Maze 2 - Solution)"));
+    assert!(text.contains(&format!("Seed: {}", runs[2].maze_options.seed)));
+
+    // Every entry of the cross reference table has to point at the start of its object
+    let xref = text.rfind("xref\n").unwrap();
+    let entries = text[xref..].lines().skip(3).take_while(|e| e.ends_with(" n "));
+    for (i, entry) in entries.enumerate() {
+        let offset: usize = entry[..10].parse().unwrap();
+        assert!(text[offset..].starts_with(&format!("{} 0 obj", i + 1)));
+    }
+}
+
+#[test]
+pub fn pdf_options() {
+    let runs = runs(1);
+    let options = PdfOptions {
+        page_size: PageSize::Letter,
+        style: WallStyle::Blocks,
+        solutions: false,
+        ..Default::default()
+    };
+    let pdf = mazes_to_pdf(&runs, &options).unwrap();
+    let text = String::from_utf8_lossy(&pdf);
+
+    assert!(text.contains("/Count 1"));
+    assert!(text.contains("/MediaBox [0 0 612 792]"));
+    assert!(text.contains(" re\n"));
+    assert!(!text.contains("Solution"));
+
+    assert!(mazes_to_pdf(&[], &options).is_err());
+}
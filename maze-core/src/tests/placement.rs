@@ -0,0 +1,61 @@
+use crate::{
+    generators::generate::generate,
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    solve::{placement::Placement, solve::{solve, SolveAlgorithm, SolveOptions}},
+    tools::{consts::MazeOptions, options::{AnimOptions, MazeData}},
+};
+
+#[test]
+pub fn placements_are_solvable() {
+    let size = Size::square(31);
+    let data = MazeData::headless(
+        &MazeOptions::rect(size.width, size.height, 42, 0),
+        &AnimOptions::new(false, false, 5000.0)
+    );
+    let maze = generate(&data).unwrap();
+
+    let custom = Placement::Custom(Point { x: 3, y: 5 }, Point { x: 27, y: 1 });
+    for placement in [Placement::Corners, Placement::Openings, Placement::Random, Placement::FarthestApart, custom] {
+        let mut maze = maze.clone();
+        let options = SolveOptions::from_placement(&mut maze, 42, SolveAlgorithm::AStar, &placement).unwrap();
+        assert_ne!(options.start, options.end);
+
+        let (legs, _) = solve(&mut maze, &data, &options).unwrap();
+        let path = &legs[0];
+        assert_eq!(path.first(), Some(&options.start));
+        assert_eq!(path.last(), Some(&options.end));
+
+        if let Some((start_opening, end_opening)) = placement.openings(size) {
+            assert_eq!(maze[&start_opening], PointState::Passage);
+            assert_eq!(maze[&end_opening], PointState::Passage);
+        }
+    }
+}
+
+#[test]
+pub fn invalid_placement_errors() {
+    let size = Size::square(11);
+    let mut maze = Grid::new(size, PointState::Wall);
+    maze[Point { x: 1, y: 1 }] = PointState::Passage;
+
+    let even = Placement::Custom(Point { x: 1, y: 1 }, Point { x: 2, y: 2 });
+    let outside = Placement::Custom(Point { x: 1, y: 1 }, Point { x: 13, y: 1 });
+    let wall = Placement::Custom(Point { x: 1, y: 1 }, Point { x: 3, y: 3 });
+    for placement in [even, outside, wall, Placement::Corners] {
+        assert!(SolveOptions::from_placement(&mut maze, 0, SolveAlgorithm::AStar, &placement).is_err());
+    }
+
+    // The border is left alone if the corners can't be used
+    let before = maze.clone();
+    assert!(SolveOptions::from_placement(&mut maze, 0, SolveAlgorithm::AStar, &Placement::Openings).is_err());
+    assert_eq!(maze, before);
+    assert!(Placement::Openings.resolve(&mut Grid::new(Size::square(1), PointState::Wall), 0).is_err());
+}
+
+#[test]
+pub fn markers_of_tiny_mazes() {
+    for size in [Size::square(0), Size::square(1), Size::new(11, 1)] {
+        let maze = Grid::new(size, PointState::Wall);
+        assert_eq!(Placement::from_markers(&maze, Some(Point { x: 1, y: 1 }), None), None);
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use image::{codecs::gif::GifDecoder, AnimationDecoder};
+
+use crate::{
+    events::recorder::Recorder,
+    run::MazeRun,
+    solve::solve::SolveAlgorithm,
+    tools::{
+        animation::{encode_apng, encode_gif, RecordOptions},
+        consts::{Maze, MazeOptions},
+        options::{AnimOptions, MazeData},
+    },
+};
+
+fn record(frame_skip: usize) -> Arc<Recorder> {
+    record_with(RecordOptions { frame_skip, scale: 3, ..Default::default() })
+}
+
+fn record_with(options: RecordOptions) -> Arc<Recorder> {
+    let recorder = Arc::new(Recorder::new(options));
+    let mut data = MazeData::headless(&MazeOptions::new(21, 9, 0), &AnimOptions::new(false, true, 5000.0));
+    data.add_observer(recorder.clone());
+
+    MazeRun::<Maze>::generate(&data, SolveAlgorithm::AStar).unwrap();
+    recorder
+}
+
+#[test]
+pub fn frame_skip() {
+    let all = record(1).frame_count();
+    let skipped = record(10).frame_count();
+
+    assert!(skipped > 1);
+    assert!(skipped < all);
+
+    let frames = record(10).get_frames();
+    assert!(frames.iter().all(|e| e.dimensions() == (21 * 3, 21 * 3)));
+}
+
+#[test]
+pub fn encode_animations() {
+    let recorder = record(25);
+    let frames = recorder.get_frames();
+    let options = recorder.get_options();
+
+    let mut gif = Vec::new();
+    encode_gif(frames.clone(), &options, &mut gif).unwrap();
+    let decoded = GifDecoder::new(gif.as_slice()).unwrap().into_frames().collect_frames().unwrap();
+    assert_eq!(decoded.len(), frames.len());
+    assert_eq!(decoded.last().unwrap().delay().numer_denom_ms(), (options.hold as u32, 1));
+
+    let mut apng = Vec::new();
+    encode_apng(frames.clone(), &options, &mut apng).unwrap();
+    let reader = png::Decoder::new(apng.as_slice()).read_info().unwrap();
+    assert_eq!(reader.info().animation_control().unwrap().num_frames, frames.len() as u32);
+}
+
+#[test]
+pub fn memory_limit() {
+    let all = record(1);
+    let frame_bytes = 21 * 21 * 4;
+    let limited = record_with(RecordOptions { frame_skip: 1, scale: 3, max_bytes: frame_bytes * 10, ..Default::default() });
+
+    // Every other frame is dropped when the limit is hit, the new frame is added on top of it
+    assert!(limited.frame_count() > 1);
+    assert!(limited.frame_count() <= 11);
+    assert!(limited.frame_count() < all.frame_count());
+    assert_eq!(limited.get_frames().first(), all.get_frames().first());
+    assert_eq!(limited.get_frames().last(), all.get_frames().last());
+}
@@ -0,0 +1,57 @@
+use crate::{
+    generators::{decimate::decimate_maze, generate::generate},
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    solve::shortest_paths::{enumerate_shortest_paths, shortest_paths},
+    tools::{consts::MazeOptions, options::{AnimOptions, MazeData}},
+};
+
+#[test]
+pub fn open_room_paths() {
+    // 3x3 cells without any inner walls, there are 4 choose 2 ways to walk from one corner to the other
+    let size = Size::square(7);
+    let mut maze = Grid::new(size, PointState::Wall);
+    for y in 1..size.height - 1 {
+        for x in 1..size.width - 1 {
+            maze[Point { x, y }] = PointState::Passage;
+        }
+    }
+
+    let start = Point { x: 1, y: 1 };
+    let end = Point { x: 5, y: 5 };
+    let res = shortest_paths(&maze, &start, &end).unwrap();
+    assert_eq!(res.length, 4);
+    assert_eq!(res.count, 6);
+    assert_eq!(res.cells.len(), 9);
+
+    let paths = enumerate_shortest_paths(&maze, &start, &end, 100).unwrap();
+    assert_eq!(paths.len(), 6);
+    assert_eq!(enumerate_shortest_paths(&maze, &start, &end, 4).unwrap().len(), 4);
+}
+
+#[test]
+pub fn braided_maze_paths() {
+    for seed in 0..5 {
+        let size = Size::square(41);
+        let data = MazeData::headless(
+            &MazeOptions::rect(size.width, size.height, seed, 100),
+            &AnimOptions::new(false, false, 5000.0)
+        );
+        let mut maze = generate(&data).unwrap();
+        decimate_maze(&data, &mut maze).unwrap();
+
+        let start = Point { x: 1, y: 1 };
+        let end = Point { x: size.width - 2, y: size.height - 2 };
+        let res = shortest_paths(&maze, &start, &end).unwrap();
+        assert!(res.count >= 1);
+
+        let paths = enumerate_shortest_paths(&maze, &start, &end, 50).unwrap();
+        assert_eq!(paths.len() as u128, res.count.min(50));
+        for (i, path) in paths.iter().enumerate() {
+            assert_eq!(path.len() as u64, res.length + 1);
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&end));
+            assert!(path.iter().all(|p| res.cells.contains(p)));
+            assert!(paths[..i].iter().all(|other| other != path));
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use crate::{
+    point::point::Point,
+    solve::solve::{solve, SolveAlgorithm, SolveOptions},
+    tools::{options::{MazeData, AnimOptions}, text::text_to_maze},
+};
+
+const SOLVED: &str = "
+###############
+#S..#   #     #
+###.# # ### # #
+#...# #     # #
+#.### ####### #
+#.#   #   #   #
+#.####### # ###
+#.#...    #   #
+#.#.#.####### #
+#...#.      # #
+# ###.####### #
+#   #.        #
+### #.#########
+#   #........E#
+###############
+";
+
+#[test]
+pub fn bench_solve() {
+    println!("Reading maze...");
+    let (mut maze, opt, expected) = text_to_maze(SOLVED, true).unwrap();
+
+    let data = MazeData::headless(
+        &opt,
+        &AnimOptions::new(false, false, 5000.0)
+    );
+    println!("Solving...");
+    let options = SolveOptions::from_placement(&mut maze, opt.seed, SolveAlgorithm::AStar, &opt.placement).unwrap();
+    let (legs, _) = solve(&mut maze, &data, &options).unwrap();
+    println!("Done.");
+
+    // Cells of the path and the passages in between them, without start and end
+    let mut found = HashSet::new();
+    for pair in legs[0].windows(2) {
+        let (p, n) = (pair[0], pair[1]);
+        found.extend([p, Point { x: (p.x + n.x) / 2, y: (p.y + n.y) / 2 }, n]);
+    }
+    found.remove(&options.start);
+    found.remove(&options.end);
+
+    assert_eq!(found, expected.into_iter().collect::<HashSet<_>>());
+}
@@ -0,0 +1,159 @@
+use crate::{
+    analysis::validate::validate,
+    generators::{decimate::decimate_maze, generate::{generate, generate_as, Generator}},
+    point::{bit_grid::BitGrid, point::Point, point_state::PointState, size::Size, sparse_grid::SparseGrid},
+    run::{load_maze, MazeRun},
+    solve::{bfs::{bfs_distances, bfs_tree}, solve::{solve, SolveAlgorithm, SolveOptions}},
+    tools::{consts::{BitMaze, MazeOptions}, options::{AnimOptions, MazeData}, storage::{to_bit_maze, MazeStorage}},
+};
+
+fn headless(opt: &MazeOptions) -> MazeData {
+    MazeData::headless(opt, &AnimOptions::new(false, false, 5000.0))
+}
+
+#[test]
+pub fn bit_grid_access() {
+    let size = Size::new(13, 11);
+    let mut bits = BitGrid::new(size, false);
+    assert_eq!(bits.count_ones(), 0);
+    assert_eq!(bits.byte_len(), 24);
+
+    bits.set(&Point { x: 12, y: 10 }, true);
+    bits.set(&Point { x: 3, y: 4 }, true);
+    bits.set(&Point { x: 3, y: 4 }, false);
+    assert!(bits.get(&Point { x: 12, y: 10 }));
+    assert!(!bits.get(&Point { x: 3, y: 4 }));
+    assert_eq!(bits.try_get(&Point { x: 13, y: 0 }), None);
+    assert!(bits.try_set(&Point { x: 0, y: 11 }, true).is_err());
+
+    // Bits after the last point are never counted
+    bits.fill(true);
+    assert_eq!(bits.count_ones(), size.len());
+    assert_eq!(BitGrid::new(size, true), bits);
+}
+
+#[test]
+pub fn sparse_grid_access() {
+    let mut grid = SparseGrid::new(Size::new(5, 3));
+    grid.insert(Point { x: 4, y: 2 }, 'b');
+    grid.insert(Point { x: 1, y: 0 }, 'a');
+    assert!(grid.set(&Point { x: 5, y: 0 }, 'c').is_err());
+    assert_eq!(grid.get(&Point { x: 1, y: 0 }), Some(&'a'));
+    assert_eq!(grid.get(&Point { x: 0, y: 0 }), None);
+
+    // Iterates row by row no matter the insertion order
+    let entries: Vec<_> = grid.iter().map(|(p, e)| (p, *e)).collect();
+    assert_eq!(entries, vec![(Point { x: 1, y: 0 }, 'a'), (Point { x: 4, y: 2 }, 'b')]);
+
+    grid.remove(&Point { x: 1, y: 0 });
+    assert_eq!(grid.len(), 1);
+    grid.clear();
+    assert!(grid.is_empty());
+}
+
+#[test]
+pub fn same_maze_in_every_storage() {
+    let opt = MazeOptions::rect(101, 61, 42, 20);
+
+    let data = headless(&opt);
+    let mut maze = generate(&data).unwrap();
+    decimate_maze(&data, &mut maze).unwrap();
+
+    let data = headless(&opt);
+    let mut bits: BitMaze = generate_as(&data).unwrap();
+    decimate_maze(&data, &mut bits).unwrap();
+
+    assert_eq!(bits.to_maze(), maze);
+    assert_eq!(to_bit_maze(&maze), bits);
+    assert_eq!(bits.count_ones(), maze.iter().filter(|e| **e == PointState::Passage).count());
+    assert_eq!(validate(&bits).unwrap(), validate(&maze).unwrap());
+}
+
+#[test]
+pub fn solve_bit_maze() {
+    let opt = MazeOptions::rect(81, 41, 7, 10);
+    let mut maze = generate(&headless(&opt)).unwrap();
+    let mut bits = to_bit_maze(&maze);
+
+    for algorithm in [SolveAlgorithm::AStar, SolveAlgorithm::DStarLite, SolveAlgorithm::AllShortest] {
+        let options = SolveOptions::from_placement(&mut maze, opt.seed, algorithm, &opt.placement).unwrap();
+        let (expected, _) = solve(&mut maze, &headless(&opt), &options).unwrap();
+        let (legs, _) = solve(&mut bits, &headless(&opt), &options).unwrap();
+        assert_eq!(legs, expected, "{} found a different path", algorithm);
+    }
+}
+
+#[test]
+pub fn bfs_tree_matches_distances() {
+    let opt = MazeOptions::rect(41, 31, 5, 30);
+    let data = headless(&opt);
+    let mut maze = generate(&data).unwrap();
+    decimate_maze(&data, &mut maze).unwrap();
+
+    let start = Point { x: 1, y: 1 };
+    let tree = bfs_tree(&maze, &start).unwrap();
+    let distances = bfs_distances(&maze, &start).unwrap();
+
+    let mut farthest = (start, 0);
+    for (p, dist) in distances.enumerate() {
+        assert_eq!(tree.distance_to(&p), *dist, "{}", p);
+        if let Some(dist) = dist {
+            assert_eq!(tree.path_to(&p).unwrap().len() as u64, dist + 1);
+            if *dist > farthest.1 {
+                farthest = (p, *dist);
+            }
+        }
+    }
+    assert_eq!(tree.get_farthest(), farthest);
+}
+
+#[test]
+pub fn bit_packed_run() {
+    let mut opt = MazeOptions::rect(81, 41, 3, 0);
+    opt.generator = Some(Generator::HuntAndKillScan);
+    let run: MazeRun = MazeRun::generate(&headless(&opt), SolveAlgorithm::AStar).unwrap();
+
+    opt.trace_search = false;
+    let bits = MazeRun::<BitMaze>::generate(&headless(&opt), SolveAlgorithm::AStar).unwrap();
+    assert_eq!(bits.maze.to_maze(), run.maze);
+    assert_eq!(bits.legs, run.legs);
+    assert!(bits.metrics.is_none());
+    assert!(bits.visual_overwrites.len() < run.visual_overwrites.len());
+
+    let dir = std::env::temp_dir().join(format!("maze-bits-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("bits.maze").to_string_lossy().to_string();
+    bits.save(&path).unwrap();
+    let (loaded, _) = load_maze(&path).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(loaded, run.maze);
+}
+
+// Highest amount of memory the process used so far
+#[cfg(target_os = "linux")]
+fn peak_memory() -> usize {
+    let status = std::fs::read_to_string("/proc/self/status").unwrap();
+    let line = status.lines().find(|e| e.starts_with("VmHWM:")).unwrap();
+    let kb: usize = line.split_whitespace().nth(1).unwrap().parse().unwrap();
+
+    kb * 1024
+}
+
+// Measures the whole process, run it on its own with `cargo test --release -- --ignored bit_packed_memory`
+#[cfg(target_os = "linux")]
+#[test]
+#[ignore]
+pub fn bit_packed_memory() {
+    let mut opt = MazeOptions::new(10001, 1, 10);
+    opt.generator = Some(Generator::HuntAndKillScan);
+    opt.trace_search = false;
+
+    let run = MazeRun::<BitMaze>::generate(&headless(&opt), SolveAlgorithm::AStar).unwrap();
+    assert!(!run.legs[0].is_empty());
+
+    // A Maze needs a byte for every point before anything is solved
+    let maze_bytes = opt.size.len() * std::mem::size_of::<PointState>();
+    let peak = peak_memory();
+    println!("Peak memory {} MB, a Maze alone takes {} MB", peak / 1_000_000, maze_bytes / 1_000_000);
+    assert!(peak < maze_bytes / 2);
+}
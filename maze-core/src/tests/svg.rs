@@ -0,0 +1,75 @@
+use crate::{
+    export::{
+        segments::{wall_blocks, wall_segments, Segment, WallStyle},
+        svg::{maze_to_svg, SvgOptions},
+    },
+    generators::generate::generate,
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    run::MazeRun,
+    solve::solve::SolveAlgorithm,
+    tools::{consts::MazeOptions, options::{AnimOptions, MazeData}},
+};
+
+fn seg(x1: usize, y1: usize, x2: usize, y2: usize) -> Segment {
+    Segment { from: Point { x: x1, y: y1 }, to: Point { x: x2, y: y2 } }
+}
+
+#[test]
+pub fn merged_segments() {
+    // The two top cells are connected, the bottom left one is closed off
+    let size = Size::square(5);
+    let mut maze = Grid::new(size, PointState::Wall);
+    for x in 1..4 {
+        maze[Point { x, y: 1 }] = PointState::Passage;
+    }
+    maze[Point { x: 1, y: 3 }] = PointState::Passage;
+
+    let segments = wall_segments(&maze).unwrap();
+    assert!(segments.contains(&seg(0, 0, 2, 0)));
+    assert!(segments.contains(&seg(0, 1, 2, 1)));
+    assert!(segments.contains(&seg(0, 2, 2, 2)));
+    assert!(segments.contains(&seg(0, 0, 0, 2)));
+    assert!(segments.contains(&seg(2, 0, 2, 2)));
+    assert!(segments.contains(&seg(1, 1, 1, 2)));
+    assert_eq!(segments.len(), 6);
+
+    let blocks = wall_blocks(&maze).unwrap();
+    let cells: usize = blocks.iter().map(|e| e.width * e.height).sum();
+    assert_eq!(cells, maze.iter().filter(|e| **e == PointState::Wall).count());
+}
+
+#[test]
+pub fn perfect_maze_wall_length() {
+    let size = Size::square(31);
+    let (cells, _) = size.cells();
+    let data = MazeData::headless(&MazeOptions::rect(size.width, size.height, 12, 0), &AnimOptions::new(false, false, 5000.0));
+    let maze = generate(&data).unwrap();
+
+    // A spanning tree removes cells - 1 of the inner walls
+    let inner = 2 * cells * (cells - 1) - (cells * cells - 1);
+    let length: usize = wall_segments(&maze)
+        .unwrap()
+        .iter()
+        .map(|e| (e.to.x - e.from.x) + (e.to.y - e.from.y))
+        .sum();
+    assert_eq!(length, 4 * cells + inner);
+}
+
+#[test]
+pub fn svg_document() {
+    let data = MazeData::headless(&MazeOptions::new(21, 4, 0), &AnimOptions::new(false, false, 5000.0));
+    let run = MazeRun::generate(&data, SolveAlgorithm::AStar).unwrap();
+    let markers = Some((run.options.start, run.options.end));
+
+    let lines = maze_to_svg(&run.maze, &run.legs, markers, &SvgOptions::default()).unwrap();
+    assert!(lines.starts_with("<svg"));
+    assert!(lines.contains("<path d=\"M"));
+    assert_eq!(lines.matches("<polyline").count(), 1);
+    assert_eq!(lines.matches("<circle").count(), 2);
+
+    let options = SvgOptions { style: WallStyle::Blocks, show_solution: false, show_markers: false, ..Default::default() };
+    let blocks = maze_to_svg(&run.maze, &run.legs, markers, &options).unwrap();
+    assert!(!blocks.contains("<polyline"));
+    assert!(!blocks.contains("<circle"));
+    assert_eq!(blocks.matches("<rect").count(), wall_blocks(&run.maze).unwrap().len() + 1);
+}
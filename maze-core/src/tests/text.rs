@@ -0,0 +1,74 @@
+use crate::{
+    generators::generate::generate,
+    point::{point::Point, point_state::PointState, size::Size},
+    run::MazeRun,
+    solve::{placement::Placement, solve::SolveAlgorithm},
+    tools::{
+        consts::MazeOptions,
+        options::{AnimOptions, MazeData},
+        text::{maze_to_text, text_to_maze, TextStyle},
+    },
+};
+
+const SMALL: &str = "
+#######
+#S#   #
+# # # #
+#   #E#
+#######
+";
+
+#[test]
+pub fn read_ascii() {
+    let (maze, opt, solution) = text_to_maze(SMALL, true).unwrap();
+    assert_eq!(opt.size, Size::new(7, 5));
+    assert_eq!(opt.placement, Placement::Custom(Point { x: 1, y: 1 }, Point { x: 5, y: 3 }));
+    assert_eq!(maze[Point { x: 1, y: 1 }], PointState::Passage);
+    assert_eq!(maze[Point { x: 2, y: 1 }], PointState::Wall);
+    assert!(solution.is_empty());
+
+    // A single marker gets its counterpart from the corners, a second one is an error
+    let (_, opt, _) = text_to_maze(&SMALL.replace('S', " "), true).unwrap();
+    assert_eq!(opt.placement, Placement::Custom(Point { x: 1, y: 1 }, Point { x: 5, y: 3 }));
+    let (_, opt, _) = text_to_maze(&SMALL.replace('E', " "), true).unwrap();
+    assert_eq!(opt.placement, Placement::Custom(Point { x: 1, y: 1 }, Point { x: 5, y: 3 }));
+    let (_, opt, _) = text_to_maze(&SMALL.replace(['S', 'E'], " "), true).unwrap();
+    assert_eq!(opt.placement, Placement::Corners);
+    assert!(text_to_maze(&SMALL.replacen("   ", "S  ", 1), true).is_err());
+    assert!(text_to_maze(&SMALL.replacen("   ", "E  ", 1), true).is_err());
+
+    assert!(text_to_maze("###\n#?#\n###\n", false).is_err());
+    assert!(text_to_maze("#####\n#   #\n#####\n#####\n", false).is_err());
+    assert!(text_to_maze("######\n#    #\n######\n", false).is_err());
+}
+
+#[test]
+pub fn round_trip() {
+    for style in [TextStyle::Ascii, TextStyle::Unicode] {
+        let data = MazeData::headless(&MazeOptions::new(25, 3, 0), &AnimOptions::new(false, false, 5000.0));
+        let run = MazeRun::generate(&data, SolveAlgorithm::AStar).unwrap();
+        let markers = Some((run.options.start, run.options.end));
+
+        let text = maze_to_text(&run.maze, &run.legs, markers, style).unwrap();
+        let (maze, opt, solution) = text_to_maze(&text, true).unwrap();
+        assert_eq!(maze, run.maze);
+        assert_eq!(opt.placement, Placement::Custom(run.options.start, run.options.end));
+
+        // Every cell of the path and the passage before it, only start and end are marked differently
+        assert_eq!(solution.len(), (run.legs[0].len() - 1) * 2 - 1);
+        assert_eq!(maze_to_text(&maze, &run.legs, markers, style).unwrap(), text);
+    }
+}
+
+#[test]
+pub fn unicode_walls() {
+    let data = MazeData::headless(&MazeOptions::new(11, 8, 0), &AnimOptions::new(false, false, 5000.0));
+    let maze = generate(&data).unwrap();
+
+    let text = maze_to_text(&maze, &[], None, TextStyle::Unicode).unwrap();
+    let lines = text.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 11);
+    assert!(lines.iter().all(|e| e.chars().count() == 21));
+    assert!(lines[0].starts_with('┌') && lines[0].ends_with('┐'));
+    assert!(lines[10].starts_with('└') && lines[10].ends_with('┘'));
+}
@@ -0,0 +1,101 @@
+use image::{DynamicImage, Rgba, RgbaImage};
+
+use crate::{
+    analysis::validate::{validate, Region},
+    generators::{decimate::decimate_maze, generate::generate},
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    tools::{consts::{Maze, MazeOptions}, image::{img_to_maze, ImportOptions}, options::{AnimOptions, MazeData}},
+};
+
+fn generate_maze(size: Size, seed: u64, decimate: usize) -> Maze {
+    let mut opt = MazeOptions::rect(size.width, size.height, seed, decimate);
+    opt.validate = true;
+
+    let data = MazeData::headless(
+        &opt,
+        &AnimOptions::new(false, false, 5000.0)
+    );
+    let mut maze = generate(&data).unwrap();
+    decimate_maze(&data, &mut maze).unwrap();
+
+    maze
+}
+
+#[test]
+pub fn generated_mazes_are_perfect() {
+    for size in [Size::square(5), Size::square(21), Size::square(51), Size::new(41, 11), Size::new(7, 31)] {
+        let (cells_x, cells_y) = size.cells();
+        for seed in 0..10 {
+            let maze = generate_maze(size, seed, 0);
+            let validation = validate(&maze).unwrap();
+
+            assert!(validation.perfect, "size {} seed {}: {}", size, seed, validation);
+            assert!(validation.is_well_formed());
+            assert_eq!(validation.regions.len(), 1);
+            assert_eq!(validation.regions[0].cells, cells_x * cells_y);
+        }
+    }
+}
+
+#[test]
+pub fn decimated_mazes_are_well_formed() {
+    for seed in 0..10 {
+        let maze = generate_maze(Size::new(41, 21), seed, 100);
+        let validation = validate(&maze).unwrap();
+
+        assert!(!validation.perfect);
+        assert!(validation.loops > 0);
+        assert!(validation.is_well_formed(), "seed {}: {}", seed, validation);
+    }
+}
+
+#[test]
+pub fn broken_mazes() {
+    let size = Size::square(21);
+    let mut maze = generate_maze(size, 3, 0);
+
+    maze[&Point { x: 0, y: 5 }] = PointState::Passage;
+    maze[&Point { x: 4, y: 4 }] = PointState::Passage;
+
+    // Walling in a cell completely cuts it off from the rest
+    let cell = Point { x: 9, y: 9 };
+    for p in [Point { x: 8, y: 9 }, Point { x: 10, y: 9 }, Point { x: 9, y: 8 }, Point { x: 9, y: 10 }] {
+        maze[&p] = PointState::Wall;
+    }
+
+    let validation = validate(&maze).unwrap();
+    assert!(!validation.perfect);
+    assert_eq!(validation.border_breaks, vec![Point { x: 0, y: 5 }]);
+    assert_eq!(validation.even_passages, vec![Point { x: 4, y: 4 }]);
+    assert!(validation.regions.len() >= 2);
+    assert!(validation.regions.contains(&Region { first: cell, cells: 1 }));
+
+    assert!(validate(&Grid::new(Size::square(20), PointState::Wall)).is_err());
+    assert!(validate(&Grid::new(Size::new(21, 20), PointState::Wall)).is_err());
+    assert!(Grid::from_vec(Size::square(21), vec![PointState::Wall; 400]).is_err());
+}
+
+#[test]
+pub fn validate_imported_image() {
+    let size = Size::square(21);
+    let maze = generate_maze(size, 7, 0);
+
+    let to_img = |maze: &Maze| {
+        let mut img = RgbaImage::new(size.width as u32, size.height as u32);
+        for (x, y, pixel) in img.enumerate_pixels_mut() {
+            let state = maze[Point { x: x as usize, y: y as usize }];
+            *pixel = if state == PointState::Wall { Rgba([0, 0, 0, 255]) } else { Rgba([255, 255, 255, 255]) };
+        }
+
+        DynamicImage::ImageRgba8(img)
+    };
+
+    let (imported, opt) = img_to_maze(&to_img(&maze), &ImportOptions::default()).unwrap();
+    assert_eq!(imported, maze);
+    assert!(opt.validate);
+
+    let mut broken = maze.clone();
+    broken[&Point { x: size.width - 1, y: 3 }] = PointState::Passage;
+    assert!(img_to_maze(&to_img(&broken), &ImportOptions::default()).is_err());
+    assert!(img_to_maze(&to_img(&broken), &ImportOptions { validate: false, ..Default::default() }).is_ok());
+}
@@ -0,0 +1,84 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    generators::generate::generate,
+    point::size::Size,
+    solve::{
+        placement::{random_cells, Placement},
+        solve::{solve, SolveAlgorithm, SolveOptions},
+        waypoints::{held_karp, nearest_neighbor, route_length, two_opt},
+    },
+    tools::{consts::MazeOptions, options::{AnimOptions, MazeData}},
+};
+
+fn random_matrix(seed: u64, n: usize) -> Vec<Vec<u64>> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let points: Vec<(i64, i64)> = (0..n).map(|_| (rng.gen_range(0..100), rng.gen_range(0..100))).collect();
+
+    points
+        .iter()
+        .map(|a| points.iter().map(|b| ((a.0 - b.0).abs() + (a.1 - b.1).abs()) as u64).collect())
+        .collect()
+}
+
+#[test]
+pub fn exact_order_is_optimal() {
+    for seed in 0..20 {
+        let dist = random_matrix(seed, 9);
+        let exact = held_karp(&dist);
+        let heuristic = two_opt(&dist, nearest_neighbor(&dist));
+
+        assert_eq!(exact.first(), Some(&0));
+        assert_eq!(exact.last(), Some(&8));
+        assert_eq!(heuristic.first(), Some(&0));
+        assert_eq!(heuristic.last(), Some(&8));
+
+        let mut visited = exact.clone();
+        visited.sort();
+        assert_eq!(visited, (0..9).collect::<Vec<_>>());
+
+        assert!(route_length(&dist, &exact) <= route_length(&dist, &heuristic));
+    }
+}
+
+#[test]
+pub fn few_waypoints() {
+    // Only the start, start and end, and a single waypoint
+    for n in 1..=3 {
+        let dist = random_matrix(3, n);
+        let order: Vec<usize> = (0..n).collect();
+        assert_eq!(two_opt(&dist, order.clone()), order);
+        assert_eq!(held_karp(&dist), order);
+    }
+
+    assert!(two_opt(&[], Vec::new()).is_empty());
+}
+
+#[test]
+pub fn route_visits_all_waypoints() {
+    let size = Size::square(41);
+    let data = MazeData::headless(
+        &MazeOptions::rect(size.width, size.height, 7, 10),
+        &AnimOptions::new(false, false, 5000.0)
+    );
+    let mut maze = generate(&data).unwrap();
+
+    // More waypoints than the exact solver handles, so the heuristic gets used as well
+    for count in [3, 16] {
+        let mut options = SolveOptions::from_placement(&mut maze, 7, SolveAlgorithm::Waypoints, &Placement::Corners).unwrap();
+        options.waypoints = random_cells(&maze, 7, count, &[options.start, options.end]).unwrap();
+
+        let (legs, _) = solve(&mut maze, &data, &options).unwrap();
+        assert_eq!(legs.len(), count + 1);
+        assert_eq!(legs.first().unwrap().first(), Some(&options.start));
+        assert_eq!(legs.last().unwrap().last(), Some(&options.end));
+
+        for pair in legs.windows(2) {
+            assert_eq!(pair[0].last(), pair[1].first());
+        }
+
+        for waypoint in &options.waypoints {
+            assert!(legs.iter().any(|leg| leg.last() == Some(waypoint)));
+        }
+    }
+}
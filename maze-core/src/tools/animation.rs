@@ -0,0 +1,115 @@
+use std::{fs::File, io::{BufWriter, Write}, path::Path};
+
+use anyhow::{anyhow, Result};
+use image::{
+    codecs::gif::{GifEncoder, Repeat},
+    Delay, Frame, RgbaImage,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecordOptions {
+    // Only every nth step of an algorithm becomes a frame
+    pub frame_skip: usize,
+    // Pixels per cell
+    pub scale: usize,
+    // Time every frame is shown in ms
+    pub frame_delay: u64,
+    // Time the last frame is shown in ms before the animation loops
+    pub hold: u64,
+    // Memory the kept frames may use, every other frame is dropped once they need more
+    pub max_bytes: usize,
+}
+
+impl Default for RecordOptions {
+    fn default() -> Self {
+        Self {
+            frame_skip: 10,
+            scale: 4,
+            frame_delay: 40,
+            hold: 2000,
+            max_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+impl RecordOptions {
+    fn delay_of(&self, index: usize, count: usize) -> u64 {
+        if index + 1 == count { self.hold } else { self.frame_delay }
+    }
+}
+
+// Writes a GIF for .gif and an APNG for .apng files. Frames are encoded one after another,
+// so only the one that is written has to be in memory.
+pub fn save_animation<I>(frames: I, options: &RecordOptions, path: &str) -> Result<()>
+where
+    I: IntoIterator<Item = RgbaImage>,
+    I::IntoIter: ExactSizeIterator,
+{
+    let extension = Path::new(path).extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase());
+    let writer = BufWriter::new(File::create(path)?);
+    match extension.as_deref() {
+        Some("gif") => encode_gif(frames, options, writer),
+        Some("apng") => encode_apng(frames, options, writer),
+        _ => Err(anyhow!("Animations can only be saved as .gif or .apng")),
+    }
+}
+
+pub fn is_animation_path(path: &str) -> bool {
+    let extension = Path::new(path).extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase());
+    matches!(extension.as_deref(), Some("gif") | Some("apng"))
+}
+
+pub fn encode_gif<I, W>(frames: I, options: &RecordOptions, writer: W) -> Result<()>
+where
+    I: IntoIterator<Item = RgbaImage>,
+    I::IntoIter: ExactSizeIterator,
+    W: Write,
+{
+    // Quantizing with the highest quality takes ages for long recordings
+    let mut encoder = GifEncoder::new_with_speed(writer, 10);
+    encoder.set_repeat(Repeat::Infinite)?;
+
+    let frames = frames.into_iter();
+    let count = frames.len();
+    let frames = frames.enumerate().map(|(i, img)| {
+        let delay = Delay::from_numer_denom_ms(options.delay_of(i, count) as u32, 1);
+        Frame::from_parts(img, 0, 0, delay)
+    });
+    encoder.encode_frames(frames)?;
+
+    Ok(())
+}
+
+pub fn encode_apng<I, W>(frames: I, options: &RecordOptions, writer: W) -> Result<()>
+where
+    I: IntoIterator<Item = RgbaImage>,
+    I::IntoIter: ExactSizeIterator,
+    W: Write,
+{
+    let mut frames = frames.into_iter().peekable();
+    let count = frames.len();
+    let (width, height) = frames
+        .peek()
+        .map(|e| e.dimensions())
+        .ok_or_else(|| anyhow!("An animation needs at least one frame"))?;
+
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_animated(count as u32, 0)?;
+
+    let mut writer = encoder.write_header()?;
+    for (i, img) in frames.enumerate() {
+        if img.dimensions() != (width, height) {
+            return Err(anyhow!("Every frame of an APNG has to be {}x{}", width, height));
+        }
+
+        // APNG delays are fractions with 16 bit parts, so long holds are stored in hundredths of a second
+        let delay = options.delay_of(i, count);
+        writer.set_frame_delay((delay / 10).min(u16::MAX as u64) as u16, 100)?;
+        writer.write_image_data(img.as_raw())?;
+    }
+    writer.finish()?;
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use std::io::{Read, Write};
+
+use anyhow::{anyhow, Result};
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+
+use crate::{
+    analysis::validate::validate,
+    generators::generate::Generator,
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    solve::placement::Placement,
+};
+
+use super::{consts::{Maze, MazeOptions}, storage::MazeStorage};
+
+pub const MAGIC: &[u8; 4] = b"MAZB";
+pub const VERSION: u8 = 1;
+pub const HEADER_LEN: usize = 44;
+
+const FLAG_COMPRESSED: u8 = 1;
+const FLAG_MARKERS: u8 = 1 << 1;
+
+// Everything besides the grid that is stored in a .maze file.
+//
+// Layout of version 1, all numbers are little endian:
+// magic (4) | version (1) | flags (1) | width (4) | height (4) | seed (8) | generator (1) | decimate (1)
+// | start x, start y, end x, end y (4 each) | payload length (4) | payload
+//
+// The payload is one bit per cell in row-major order, least significant bit first, 1 for passages.
+// It is deflated with zlib if the compressed flag is set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BinaryHeader {
+    pub version: u8,
+    pub width: usize,
+    pub height: usize,
+    pub seed: u64,
+    pub generator: Option<Generator>,
+    pub decimate: usize,
+    pub markers: Option<(Point, Point)>,
+    pub compressed: bool,
+}
+
+impl BinaryHeader {
+    pub fn from_options(opt: &MazeOptions, markers: Option<(Point, Point)>, compressed: bool) -> Self {
+        Self {
+            version: VERSION,
+            width: opt.size.width,
+            height: opt.size.height,
+            seed: opt.seed,
+            generator: opt.generator,
+            decimate: opt.decimate,
+            markers,
+            compressed,
+        }
+    }
+
+    pub fn to_options(&self) -> MazeOptions {
+        let mut opt = MazeOptions::rect(self.width, self.height, self.seed, self.decimate);
+        opt.generator = self.generator;
+        if let Some((start, end)) = self.markers {
+            opt.placement = Placement::Custom(start, end);
+        }
+
+        opt
+    }
+}
+
+fn generator_id(generator: Option<Generator>) -> u8 {
+    match generator {
+        None => 0,
+        Some(Generator::HuntAndKill) => 1,
+        Some(Generator::HuntAndKillScan) => 2,
+        Some(Generator::Parallel) => 3,
+    }
+}
+
+fn generator_from_id(id: u8) -> Result<Option<Generator>> {
+    match id {
+        0 => Ok(None),
+        1 => Ok(Some(Generator::HuntAndKill)),
+        2 => Ok(Some(Generator::HuntAndKillScan)),
+        3 => Ok(Some(Generator::Parallel)),
+        _ => Err(anyhow!("Unknown generator id {}", id)),
+    }
+}
+
+pub fn pack_bits<M: MazeStorage>(maze: &M) -> Vec<u8> {
+    let size = maze.get_size();
+    let mut out = vec![0u8; size.len().div_ceil(8)];
+    for i in 0..size.len() {
+        if maze.is_passage(&Point { x: i % size.width, y: i / size.width }) {
+            out[i / 8] |= 1 << (i % 8);
+        }
+    }
+
+    out
+}
+
+pub fn unpack_bits(bytes: &[u8], size: Size) -> Result<Maze> {
+    let cells = size.len();
+    if bytes.len() != cells.div_ceil(8) {
+        return Err(anyhow!("Expected {} bytes for {} cells but got {}", cells.div_ceil(8), cells, bytes.len()));
+    }
+
+    let maze = (0..cells)
+        .map(|i| if bytes[i / 8] & (1 << (i % 8)) != 0 { PointState::Passage } else { PointState::Wall })
+        .collect();
+    Grid::from_vec(size, maze)
+}
+
+pub fn maze_to_binary<M: MazeStorage>(maze: &M, header: &BinaryHeader) -> Result<Vec<u8>> {
+    if maze.get_size() != Size::new(header.width, header.height) {
+        return Err(anyhow!("Maze has a size of {} but the header says {}x{}", maze.get_size(), header.width, header.height));
+    }
+
+    let mut payload = pack_bits(maze);
+    if header.compressed {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&payload)?;
+        payload = encoder.finish()?;
+    }
+
+    let mut flags = 0;
+    if header.compressed {
+        flags |= FLAG_COMPRESSED;
+    }
+    if header.markers.is_some() {
+        flags |= FLAG_MARKERS;
+    }
+
+    let (start, end) = header.markers.unwrap_or((Point { x: 0, y: 0 }, Point { x: 0, y: 0 }));
+    let mut out = Vec::with_capacity(HEADER_LEN + payload.len());
+    out.extend_from_slice(MAGIC);
+    out.push(VERSION);
+    out.push(flags);
+    out.extend_from_slice(&u32::try_from(header.width)?.to_le_bytes());
+    out.extend_from_slice(&u32::try_from(header.height)?.to_le_bytes());
+    out.extend_from_slice(&header.seed.to_le_bytes());
+    out.push(generator_id(header.generator));
+    out.push(u8::try_from(header.decimate)?);
+    for e in [start.x, start.y, end.x, end.y] {
+        out.extend_from_slice(&u32::try_from(e)?.to_le_bytes());
+    }
+    out.extend_from_slice(&u32::try_from(payload.len())?.to_le_bytes());
+    out.extend_from_slice(&payload);
+
+    Ok(out)
+}
+
+// Mazes are checked the same way as imported images if `validate_maze` is set
+pub fn binary_to_maze(bytes: &[u8], validate_maze: bool) -> Result<(Maze, MazeOptions, BinaryHeader)> {
+    if bytes.len() < HEADER_LEN || &bytes[0..4] != MAGIC {
+        return Err(anyhow!("Not a maze file"));
+    }
+
+    let version = bytes[4];
+    if version != VERSION {
+        return Err(anyhow!("Unsupported maze file version {}, only {} can be read", version, VERSION));
+    }
+
+    let u32_at = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap()) as usize;
+    let flags = bytes[5];
+    let width = u32_at(6);
+    let height = u32_at(10);
+    let seed = u64::from_le_bytes(bytes[14..22].try_into().unwrap());
+    let generator = generator_from_id(bytes[22])?;
+    let decimate = bytes[23] as usize;
+    let start = Point { x: u32_at(24), y: u32_at(28) };
+    let end = Point { x: u32_at(32), y: u32_at(36) };
+    let payload_len = u32_at(40);
+    if !Size::new(width, height).is_valid() {
+        return Err(anyhow!("{}x{} is not a valid maze size", width, height));
+    }
+
+    let payload = bytes
+        .get(HEADER_LEN..HEADER_LEN + payload_len)
+        .ok_or_else(|| anyhow!("Maze file is truncated"))?;
+
+    let compressed = flags & FLAG_COMPRESSED != 0;
+    let mut packed = payload.to_vec();
+    if compressed {
+        let expected = width
+            .checked_mul(height)
+            .ok_or_else(|| anyhow!("Maze of {}x{} is too large", width, height))?
+            .div_ceil(8);
+
+        // Never inflate more than the grid can hold, a tiny file could otherwise decompress to gigabytes
+        packed = Vec::new();
+        ZlibDecoder::new(payload).take(expected as u64 + 1).read_to_end(&mut packed)?;
+        if packed.len() > expected {
+            return Err(anyhow!("Payload decompresses to more than the {} bytes a {}x{} maze needs", expected, width, height));
+        }
+    }
+
+    let maze = unpack_bits(&packed, Size::new(width, height))?;
+    let markers = if flags & FLAG_MARKERS != 0 { Some((start, end)) } else { None };
+    let header = BinaryHeader { version, width, height, seed, generator, decimate, markers, compressed };
+
+    let mut opt = header.to_options();
+    opt.validate = validate_maze;
+    if let Some(placement) = Placement::from_markers(&maze, markers.map(|e| e.0), markers.map(|e| e.1)) {
+        opt.placement = placement;
+    }
+
+    if validate_maze {
+        let validation = validate(&maze)?;
+        if !validation.is_well_formed_with(markers) {
+            return Err(anyhow!("File is not a valid maze. {}", validation));
+        }
+    }
+
+    Ok((maze, opt, header))
+}
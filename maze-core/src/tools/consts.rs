@@ -0,0 +1,117 @@
+use std::sync::{Arc, RwLock};
+
+use anyhow::Result;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{generators::generate::Generator, point::{bit_grid::BitGrid, grid::Grid, point_state::{PointState, VisualIndicator}, size::Size, sparse_grid::SparseGrid}, solve::placement::Placement};
+
+use super::options::MazeData;
+
+
+
+#[derive(Debug, Clone)]
+pub struct MazeOptions {
+    pub size: Size,
+    pub seed: u64,
+    pub decimate: usize,
+    // None for mazes that were loaded from a format that doesn't know how they were made
+    pub generator: Option<Generator>,
+    pub placement: Placement,
+    // Amount of random cells that have to be visited when solving with waypoints
+    pub waypoints: usize,
+    // Amount of agents that are routed at the same time by the multi agent solver
+    pub agents: usize,
+    // Runs the validator on every generated maze and fails if it isn't perfect
+    pub validate: bool,
+    // Solves on the reduced junction graph if the algorithm supports it
+    pub use_graph: bool,
+    // Threads used by Generator::Parallel, the same seed only gives the same maze with the same amount of threads
+    pub threads: usize,
+    // Keeps every cell the solver searched through in the overlay, huge mazes turn this off to save the memory
+    pub trace_search: bool,
+    // Computes the MazeMetrics of a run, which needs a few bytes for every point of the maze
+    pub analyze: bool,
+    seeder: StdRng,
+}
+
+impl Default for MazeOptions {
+    fn default() -> Self {
+        let rand = rand::random();
+        Self {
+            size: Size::square(50),
+            decimate: 2,
+            seed: rand,
+            generator: Some(Generator::HuntAndKill),
+            placement: Placement::Corners,
+            waypoints: 5,
+            agents: 4,
+            validate: false,
+            use_graph: false,
+            threads: default_threads(),
+            trace_search: true,
+            analyze: false,
+            seeder: StdRng::seed_from_u64(rand)
+        }
+    }
+}
+
+impl MazeOptions {
+    pub fn new(size: usize, seed: u64, decimate: usize) -> Self {
+        Self::rect(size, size, seed, decimate)
+    }
+
+    pub fn rect(width: usize, height: usize, seed: u64, decimate: usize) -> Self {
+        Self {
+            size: Size { width, height },
+            decimate,
+            seed,
+            generator: Some(Generator::HuntAndKill),
+            placement: Placement::Corners,
+            waypoints: 5,
+            agents: 4,
+            validate: false,
+            use_graph: false,
+            threads: default_threads(),
+            trace_search: true,
+            analyze: false,
+            seeder: StdRng::seed_from_u64(seed)
+        }
+    }
+}
+
+pub type MazeOptionsArc = Arc<RwLock<MazeOptions>>;
+pub type Maze = Grid<PointState>;
+pub type VisualOverwrites = SparseGrid<VisualIndicator>;
+// One bit per point, for mazes that are too big to keep a whole PointState for every point
+pub type BitMaze = BitGrid;
+
+
+
+// One thread per core
+pub fn default_threads() -> usize {
+    std::thread::available_parallelism().map(|e| e.get()).unwrap_or(1)
+}
+
+pub fn check_size(data: &MazeData) {
+    let mut s = data.get_opt();
+    s.size = s.size.to_odd();
+
+    data.write_opt(&s);
+}
+
+pub fn get_size(data: &MazeData) -> Result<Size> {
+    let opt = data.get_opt();
+    Ok(opt.size)
+}
+
+pub fn get_seeder(data: &MazeData) -> StdRng {
+    let opt = data.get_opt();
+    opt.seeder
+}
+
+pub fn set_seeder(data: &MazeData, seeder: StdRng) {
+    let mut e = data.get_opt();
+    e.seeder = seeder;
+
+    data.write_opt(&e);
+}
\ No newline at end of file
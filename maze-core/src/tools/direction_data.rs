@@ -17,7 +17,7 @@ impl DirectionData {
 
     // Converts for example x: 10 and y: -10 to x: 1 y: -1
     pub fn normalize(&self) -> Self {
-        return DirectionData {
+        DirectionData {
             x: if self.x == 0 { 0 } else { self.x.abs() / self.x },
             y: if self.y == 0 { 0 } else { self.y.abs() / self.y },
             dir: self.dir
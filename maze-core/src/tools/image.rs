@@ -0,0 +1,179 @@
+use anyhow::{Result, anyhow};
+use image::{ImageBuffer, RgbaImage, Rgba, DynamicImage};
+
+use crate::{analysis::validate::validate, point::{grid::Grid, point::Point, point_state::PointState, size::Size}, solve::placement::Placement};
+
+use super::{color::Color, consts::{Maze, MazeOptions, VisualOverwrites}, math::vec2_to_numb, render::render_maze};
+
+pub fn maze_to_img(maze: &Maze, visual_overwrites: &VisualOverwrites) -> Result<RgbaImage> {
+    let pixels = render_maze(maze, visual_overwrites);
+    Ok(pixels_to_img(maze.get_size(), &pixels, 1))
+}
+
+// Every cell becomes a block of scale x scale pixels
+pub fn pixels_to_img(size: Size, pixels: &[Color], scale: usize) -> RgbaImage {
+    let scale = scale.max(1);
+
+    let mut out: RgbaImage = ImageBuffer::new((size.width * scale) as u32, (size.height * scale) as u32);
+    for pixel in out.enumerate_pixels_mut() {
+        let index = vec2_to_numb(pixel.0 as usize / scale, pixel.1 as usize / scale, size.width);
+        *pixel.2 = Rgba(pixels[index].to_array());
+    }
+
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImportOptions {
+    // Pixels with a luminance below the threshold are walls
+    pub threshold: u8,
+    // Width of a cell in pixels, detected from the image if not given
+    pub cell_size: Option<usize>,
+    pub validate: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self { threshold: 128, cell_size: None, validate: true }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pixel {
+    Wall,
+    Passage,
+    Start,
+    End,
+}
+
+const START_COLOR: Color = Color::from_rgb(255, 0, 0);
+const END_COLOR: Color = Color::from_rgb(0, 255, 0);
+
+impl Pixel {
+    fn from_rgba(color: &Rgba<u8>, threshold: u8) -> Self {
+        let [r, g, b, a] = color.0;
+        let is_close = |target: &Color| {
+            let target = target.to_array();
+            a >= 128 && r.abs_diff(target[0]) as u32 + g.abs_diff(target[1]) as u32 + b.abs_diff(target[2]) as u32 <= 96
+        };
+
+        if is_close(&START_COLOR) {
+            return Pixel::Start;
+        }
+        if is_close(&END_COLOR) {
+            return Pixel::End;
+        }
+
+        // Solutions and search overlays are only drawn on passages, however dark their colour is
+        if a >= 128 && r.max(g).max(b) - r.min(g).min(b) > 96 {
+            return Pixel::Passage;
+        }
+
+        // Transparent pixels are drawn on top of white
+        let luminance = 0.2126 * r as f64 + 0.7152 * g as f64 + 0.0722 * b as f64;
+        let alpha = a as f64 / 255.0;
+        let luminance = luminance * alpha + 255.0 * (1.0 - alpha);
+        if luminance < threshold as f64 {
+            Pixel::Wall
+        } else {
+            Pixel::Passage
+        }
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// Every run of equal pixels in a row or column spans a whole number of cells, so the cell size is the
+// greatest common divisor of all run lengths
+fn detect_cell_size(pixels: &[Vec<Pixel>]) -> usize {
+    let width = pixels.first().map_or(0, |e| e.len());
+
+    let mut size = 0;
+    let mut add_runs = |line: &mut dyn Iterator<Item = Pixel>| {
+        let mut previous = None;
+        let mut run = 0;
+        for pixel in line {
+            if previous.is_some() && previous != Some(pixel) {
+                size = gcd(size, run);
+                run = 0;
+            }
+            previous = Some(pixel);
+            run += 1;
+        }
+        size = gcd(size, run);
+    };
+
+    for row in pixels {
+        add_runs(&mut row.iter().copied());
+    }
+    for x in 0..width {
+        add_runs(&mut pixels.iter().map(|e| e[x]));
+    }
+
+    size.max(1)
+}
+
+// Imported mazes may contain loops, but the other checks of the validator have to pass if `validate` is set.
+// Red and green cells become the start and end of a custom placement, the corners are used for the one that
+// isn't marked.
+pub fn img_to_maze(img: &DynamicImage, opts: &ImportOptions) -> Result<(Maze, MazeOptions)> {
+    let img = img.to_rgba8();
+    let (width, height) = (img.width() as usize, img.height() as usize);
+    let pixels = (0..height)
+        .map(|y| (0..width).map(|x| Pixel::from_rgba(img.get_pixel(x as u32, y as u32), opts.threshold)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let cell_size = opts.cell_size.unwrap_or_else(|| detect_cell_size(&pixels));
+    if cell_size == 0 || width % cell_size != 0 || height % cell_size != 0 {
+        return Err(anyhow!("Image of {}x{} pixels can not be split into cells of {} pixels", width, height, cell_size));
+    }
+
+    let (cells_x, cells_y) = (width / cell_size, height / cell_size);
+    let is_odd = cells_x % 2 == 1 && cells_y % 2 == 1;
+    if !is_odd || cells_x < 3 || cells_y < 3 {
+        return Err(anyhow!("Invalid image dimensions, {}x{} cells have to be odd and at least 3", cells_x, cells_y));
+    }
+
+    let size = Size::new(cells_x, cells_y);
+    let mut opt = MazeOptions::rect(cells_x, cells_y, u64::MAX, 0);
+    opt.generator = None;
+    opt.validate = opts.validate;
+
+    let mut maze = Grid::new(size, PointState::Wall);
+    let mut start = None;
+    let mut end = None;
+    for y in 0..cells_y {
+        for x in 0..cells_x {
+            // The centre of a cell is the least likely to be blurred by scaling
+            let pixel = pixels[y * cell_size + cell_size / 2][x * cell_size + cell_size / 2];
+            let p = Point { x, y };
+            match pixel {
+                Pixel::Wall => {}
+                Pixel::Passage => {}
+                Pixel::Start if start.is_some() => return Err(anyhow!("Found a second start at {}", p)),
+                Pixel::End if end.is_some() => return Err(anyhow!("Found a second end at {}", p)),
+                Pixel::Start => start = Some(p),
+                Pixel::End => end = Some(p),
+            }
+
+            if pixel != Pixel::Wall {
+                maze[p] = PointState::Passage;
+            }
+        }
+    }
+
+    if let Some(placement) = Placement::from_markers(&maze, start, end) {
+        opt.placement = placement;
+    }
+
+    if opts.validate {
+        let validation = validate(&maze)?;
+        if !validation.is_well_formed_with(opt.placement.markers(size)) {
+            return Err(anyhow!("Image is not a valid maze. {}", validation));
+        }
+    }
+
+    Ok((maze, opt))
+}
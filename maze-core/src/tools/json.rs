@@ -0,0 +1,208 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    analysis::{metrics::{analyze, MazeMetrics}, validate::validate},
+    generators::generate::Generator,
+    point::{grid::Grid, sparse_grid::SparseGrid, point::Point, point_state::PointState, size::Size},
+    run::{draw_solution, MazeRun},
+    solve::{multi_agent::Agent, placement::{check_cell, Placement}, solve::{SolveAlgorithm, SolveOptions}},
+};
+
+use super::{consts::{default_threads, Maze, MazeOptions}, math::points_to_dir, matrix::has_passage_between};
+
+// Version 1 only stored a single size for square mazes
+pub const JSON_VERSION: u32 = 2;
+// Describes `MazeDocument`, keep both in sync
+pub const JSON_SCHEMA: &str = include_str!("../../maze.schema.json");
+
+// The MazeOptions that are needed to generate the same maze again
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MazeParameters {
+    pub seed: u64,
+    pub decimate: usize,
+    pub placement: Placement,
+    pub waypoints: usize,
+    pub agents: usize,
+    pub validate: bool,
+    pub use_graph: bool,
+    // Only changes the maze if it was generated in parallel
+    #[serde(default = "default_threads")]
+    pub threads: usize,
+}
+
+impl From<&MazeOptions> for MazeParameters {
+    fn from(opt: &MazeOptions) -> Self {
+        Self {
+            seed: opt.seed,
+            decimate: opt.decimate,
+            placement: opt.placement,
+            waypoints: opt.waypoints,
+            agents: opt.agents,
+            validate: opt.validate,
+            use_graph: opt.use_graph,
+            threads: opt.threads,
+        }
+    }
+}
+
+// A solved maze with everything that is needed to show it again, see maze.schema.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MazeDocument {
+    pub version: u32,
+    #[serde(default)]
+    pub width: usize,
+    #[serde(default)]
+    pub height: usize,
+    #[serde(default, skip_serializing)]
+    size: Option<usize>,
+    // One string per row, `#` for walls and spaces for passages
+    pub grid: Vec<String>,
+    pub start: Point,
+    pub end: Point,
+    pub generator: Option<Generator>,
+    pub parameters: MazeParameters,
+    pub algorithm: SolveAlgorithm,
+    #[serde(default)]
+    pub waypoints: Vec<Point>,
+    #[serde(default)]
+    pub agents: Vec<Agent>,
+    // Every leg of the solution, empty if the maze wasn't solved
+    #[serde(default)]
+    pub solution: Vec<Vec<Point>>,
+    pub metrics: Option<MazeMetrics>,
+}
+
+pub fn grid_to_rows(maze: &Maze) -> Vec<String> {
+    maze.rows()
+        .map(|row| row.iter().map(|e| if *e == PointState::Wall { '#' } else { ' ' }).collect())
+        .collect()
+}
+
+pub fn rows_to_grid(size: Size, rows: &[String]) -> Result<Maze> {
+    if rows.len() != size.height {
+        return Err(anyhow!("Grid has {} rows but the size is {}", rows.len(), size));
+    }
+
+    let mut maze = Grid::new(size, PointState::Wall);
+    for (y, row) in rows.iter().enumerate() {
+        if row.chars().count() != size.width {
+            return Err(anyhow!("Row {} has {} cells but should have {}", y, row.chars().count(), size.width));
+        }
+
+        for (x, c) in row.chars().enumerate() {
+            match c {
+                '#' => {}
+                ' ' => maze[Point { x, y }] = PointState::Passage,
+                _ => return Err(anyhow!("Unknown cell '{}' in row {}", c, y)),
+            }
+        }
+    }
+
+    Ok(maze)
+}
+
+// Every point of the document has to be a passage cell of the grid and the legs of the solution have to
+// go from cell to neighbouring cell without crossing a wall
+fn check_points(maze: &Maze, doc: &MazeDocument) -> Result<()> {
+    let agents = doc.agents.iter().flat_map(|e| [e.start, e.end]);
+    for p in [doc.start, doc.end].into_iter().chain(doc.waypoints.iter().copied()).chain(agents) {
+        check_cell(maze, &p)?;
+    }
+
+    for (i, leg) in doc.solution.iter().enumerate() {
+        for p in leg {
+            check_cell(maze, p).map_err(|e| anyhow!("Leg {} of the solution is broken: {}", i, e))?;
+        }
+
+        for pair in leg.windows(2) {
+            let (p, n) = (pair[0], pair[1]);
+            let is_open = match points_to_dir(&n, &p) {
+                Some(dir) => has_passage_between(maze, &p, &dir)? == Some(true),
+                None => false,
+            };
+            if !is_open {
+                return Err(anyhow!("Leg {} of the solution jumps from {} to {}", i, p, n));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl MazeRun {
+    pub fn to_document(&self) -> MazeDocument {
+        MazeDocument {
+            version: JSON_VERSION,
+            width: self.size.width,
+            height: self.size.height,
+            size: None,
+            grid: grid_to_rows(&self.maze),
+            start: self.options.start,
+            end: self.options.end,
+            generator: self.maze_options.generator,
+            parameters: MazeParameters::from(&self.maze_options),
+            algorithm: self.options.algorithm,
+            waypoints: self.options.waypoints.clone(),
+            agents: self.options.agents.clone(),
+            solution: self.legs.clone(),
+            metrics: self.metrics.clone(),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.to_document())?)
+    }
+
+    // The overlay is rebuilt from the solution, what the solver searched through is not stored
+    pub fn from_document(doc: &MazeDocument) -> Result<Self> {
+        let size = match (doc.version, doc.size) {
+            (1, Some(size)) => Size::square(size),
+            (JSON_VERSION, _) => Size::new(doc.width, doc.height),
+            _ => return Err(anyhow!("Unsupported maze document version {}, only up to {} can be read", doc.version, JSON_VERSION)),
+        };
+
+        let maze = rows_to_grid(size, &doc.grid)?;
+        let params = &doc.parameters;
+
+        let validation = validate(&maze)?;
+        if !validation.is_well_formed_with(Some((doc.start, doc.end))) {
+            return Err(anyhow!("Document does not contain a valid maze. {}", validation));
+        }
+        check_points(&maze, doc)?;
+
+        let mut maze_options = MazeOptions::rect(size.width, size.height, params.seed, params.decimate);
+        maze_options.generator = doc.generator;
+        maze_options.threads = params.threads;
+        maze_options.placement = params.placement;
+        maze_options.waypoints = params.waypoints;
+        maze_options.agents = params.agents;
+        maze_options.validate = params.validate;
+        maze_options.use_graph = params.use_graph;
+
+        let options = SolveOptions {
+            start: doc.start,
+            end: doc.end,
+            algorithm: doc.algorithm,
+            placement: params.placement,
+            waypoints: doc.waypoints.clone(),
+            agents: doc.agents.clone(),
+            use_graph: params.use_graph,
+        };
+
+        let mut visual_overwrites = SparseGrid::new(size);
+        draw_solution(&doc.solution, &options, &mut visual_overwrites)?;
+
+        let metrics = match &doc.metrics {
+            Some(metrics) => Some(metrics.clone()),
+            None => Some(analyze(&maze, &doc.start, &doc.end)?),
+        };
+
+        Ok(Self { maze, size, maze_options, options, legs: doc.solution.clone(), visual_overwrites, metrics })
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        let doc: MazeDocument = serde_json::from_str(json)?;
+        Self::from_document(&doc)
+    }
+}
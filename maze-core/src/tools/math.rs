@@ -0,0 +1,70 @@
+use rand_distr::num_traits::Pow;
+
+use crate::point::{point::Point, direction::{Direction, DIRECTION_VEC}};
+
+// Matrices are stored row by row, so only the width is needed to find a point
+pub fn vec2_to_numb(x: usize, y: usize, width: usize) -> usize {
+    y * width + x
+}
+
+pub fn numb_to_vec2(numb: usize, width: usize) -> Point {
+    Point {x: numb % width, y: numb / width }
+}
+
+pub fn point_to_numb(p: &Point, width: usize) -> usize {
+    vec2_to_numb(p.x, p.y, width)
+}
+
+// Derives a new seed from the given one, used when parts of a maze need their own seed.
+// Splitmix64 over all values, unlike the std hashers the result never changes between releases
+pub fn mix_seed(seed: u64, values: &[u64]) -> u64 {
+    let mut hash = seed;
+    for value in values {
+        hash = hash.wrapping_add(*value).wrapping_add(0x9e37_79b9_7f4a_7c15);
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        hash ^= hash >> 31;
+    }
+
+    hash
+}
+
+pub fn get_dist(src: &Point, dest: &Point) -> u64 {
+    let Point { x: s_x, y: s_y} = src;
+    let Point { x: d_x, y: d_y} = dest;
+
+    let s_x = *s_x as i32;
+    let s_y = *s_y as i32;
+
+    let d_x = *d_x as i32;
+    let d_y = *d_y as i32;
+
+    ((s_x - d_x).abs() + (s_y - d_y).abs()).try_into().unwrap()
+}
+
+pub fn linear_dist(src: &Point, dest: &Point) -> f64 {
+    let Point { x: s_x, y: s_y} = src;
+    let Point { x: d_x, y: d_y} = dest;
+
+    let s_x = *s_x as f64;
+    let s_y = *s_y as f64;
+
+    let d_x = *d_x as f64;
+    let d_y = *d_y as f64;
+
+    let out: f64 = (d_x - s_x).pow(2) + (d_y - s_y).pow(2);
+    out.sqrt()
+}
+
+pub fn points_to_dir(a: &Point, b: &Point) -> Option<Direction> {
+    let mut dir = None;
+    for el in DIRECTION_VEC.iter() {
+        let x = a.x as i32 - b.x as i32;
+        let y = a.y as i32 - b.y as i32;
+
+        if x == el.x && y == el.y {
+            dir = Some(el.dir);
+        }
+    }
+    dir
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use anyhow::Result;
+
+use crate::point::{point::Point, point_state::PointState, direction::{Direction, DIRECTION_VEC}, size::Size};
+
+use super::{direction_data::DirectionData, storage::MazeStorage};
+
+pub fn go_to_dir(size: &Size, point: &Point, dir: &Direction) -> Option<Point> {
+    let mut pos = None;
+    let Point { x, y } = *point;
+
+    for info in DIRECTION_VEC.iter() {
+        if info.dir == *dir {
+            let x = x as i32 + info.x;
+            let y = y as i32 + info.y;
+            if x < 0 || y < 0 || x >= size.width as i32 || y >= size.height as i32 {
+                break;
+            }
+
+            let x = x as usize;
+            let y = y as usize;
+            pos = Some(Point { x, y });
+            break;
+        }
+    }
+
+    pos
+}
+
+pub fn get_surrounding_walls<M: MazeStorage>(maze: &M, point: &Point) -> Result<Vec<Direction>> {
+    get_available_dirs_state(maze, point, PointState::Wall)
+}
+
+pub fn get_available_dirs_state<M: MazeStorage>(
+    maze: &M,
+    point: &Point,
+    desired_state: PointState,
+) -> anyhow::Result<Vec<Direction>> {
+    let available = maze.get_size().cell_neighbours(point)
+        .filter(|(_, p)| maze.get_state(p) == desired_state)
+        .map(|(dir, _)| dir)
+        .collect();
+
+    Ok(available)
+}
+
+// Same as get_available_dirs_state without collecting the directions
+pub fn count_dirs_state<M: MazeStorage>(maze: &M, point: &Point, desired_state: PointState) -> usize {
+    maze.get_size().cell_neighbours(point)
+        .filter(|(_, p)| maze.get_state(p) == desired_state)
+        .count()
+}
+
+// I hate carrying around data but I cant store it in consts file as well because I want to have multiple threads running mazes later :(
+pub fn get_pos_between(size: &Size, src: &Point, dir: &Direction) -> Result<Option<Point>> {
+    let dest = go_to_dir(size, src, dir);
+    if dest.is_none() {
+        return Ok(None);
+    }
+
+    let DirectionData { x: dir_x, y: dir_y, .. } = dir.to_data().normalize();
+    Ok(src.add(-dir_x, -dir_y))
+}
+
+pub fn has_passage_between<M: MazeStorage>(maze: &M, src: &Point, dir: &Direction) -> Result<Option<bool>> {
+    let between = get_pos_between(&maze.get_size(), src, dir)?;
+    if between.is_none() {
+        return Ok(Some(false));
+    }
+
+    let between = between.unwrap();
+    Ok(Some(maze.is_passage(&between)))
+}
+
+// Returns every neighbouring cell that can be reached from the given point without crossing a wall
+pub fn get_connected_cells<M: MazeStorage>(maze: &M, point: &Point) -> Result<Vec<Point>> {
+    let mut cells = Vec::with_capacity(DIRECTION_VEC.len());
+    for (dir, neighbor) in maze.get_size().cell_neighbours(point) {
+        if !maze.is_passage(&neighbor) {
+            continue;
+        }
+
+        let has_passage = has_passage_between(maze, point, &dir)?.unwrap_or(false);
+        if !has_passage {
+            continue;
+        }
+
+        cells.push(neighbor);
+    }
+
+    Ok(cells)
+}
@@ -0,0 +1,13 @@
+pub mod color;
+pub mod consts;
+pub mod render;
+pub mod math;
+pub mod direction_data;
+pub mod matrix;
+pub mod options;
+pub mod image;
+pub mod json;
+pub mod binary;
+pub mod text;
+pub mod animation;
+pub mod storage;
\ No newline at end of file
@@ -0,0 +1,168 @@
+use std::sync::{RwLock, Arc};
+
+
+use crate::{point::point::Point, solve::d_star::WallToggles};
+use anyhow::{anyhow, Result};
+
+use crate::events::event::{MazeEvent, MazeObserver};
+
+use super::consts::{MazeOptionsArc, MazeOptions};
+
+
+pub type ShouldExit = Arc<RwLock<bool>>;
+pub type ShowDebug = Arc<RwLock<bool>>;
+pub type ShowAnim = Arc<RwLock<bool>>;
+pub type SpeedAnim = Arc<RwLock<f64>>;
+pub type Observers = Vec<Arc<dyn MazeObserver>>;
+
+#[derive(Clone, Debug)]
+pub struct AnimOptions {
+    show_debug: ShowDebug,
+    show_anim: ShowAnim,
+    speed: SpeedAnim,
+}
+
+impl Default for AnimOptions {
+    fn default() -> Self {
+        Self {
+            show_debug: Arc::new(RwLock::new(true)),
+            show_anim: Arc::new(RwLock::new(true)),
+            speed: Arc::new(RwLock::new(1.0))
+        }
+    }
+}
+
+impl AnimOptions {
+    pub fn new(show_debug: bool, show_anim: bool, speed: f64) -> Self {
+        Self {
+            show_debug: Arc::new(RwLock::new(show_debug)),
+            show_anim: Arc::new(RwLock::new(show_anim)),
+            speed: Arc::new(RwLock::new(speed))
+        }
+    }
+
+    pub fn show_anim(&self) -> bool {
+        return *self.show_anim.read().unwrap();
+    }
+
+    pub fn speed(&self) -> f64 {
+        return *self.speed.read().unwrap();
+    }
+}
+
+#[derive(Clone)]
+pub struct MazeData {
+    should_exit: ShouldExit,
+    maze_opt: MazeOptionsArc,
+    anim: AnimOptions,
+    // Everything that wants to know what the algorithms are doing, empty for headless runs
+    observers: Observers,
+    // Where D* Lite gets the walls to toggle from while it is solving, None if nobody can toggle any
+    wall_toggles: Option<Arc<dyn WallToggles>>
+}
+
+impl MazeData {
+    pub fn new(observers: &Observers, maze_opt: &MazeOptions, anim_opt: &AnimOptions) -> Self {
+        let mut data = Self::headless(maze_opt, anim_opt);
+        data.observers = observers.clone();
+
+        data
+    }
+
+    pub fn headless(maze_opt: &MazeOptions, anim_opt: &AnimOptions) -> Self {
+        let should_exit = Arc::new(RwLock::new(false));
+        Self {
+            observers: Vec::new(),
+            maze_opt: Arc::new(RwLock::new(maze_opt.clone())),
+            anim: anim_opt.clone(),
+            should_exit: should_exit.clone(),
+            wall_toggles: None
+        }
+    }
+
+    pub fn get_opt(&self) -> MazeOptions {
+        self.maze_opt.read().unwrap().clone()
+    }
+
+    pub fn write_opt(&self, opt: &MazeOptions) {
+        *self.maze_opt.write().unwrap() = opt.clone();
+    }
+
+
+    pub fn set_show_debug(&self, debug: bool) {
+        *self.anim.show_debug.write().unwrap() = debug;
+    }
+
+    pub fn show_debug(&self) -> bool {
+        return *self.anim.show_debug.read().unwrap();
+    }
+
+    pub fn set_show_anim(&self, anim: bool) {
+        *self.anim.show_anim.write().unwrap() = anim;
+    }
+
+    pub fn show_anim(&self) -> bool {
+        self.anim.show_anim()
+    }
+
+    pub fn speed_anim(&self) -> f64 {
+        self.anim.speed()
+    }
+
+    pub fn set_speed_anim(&self, speed: f64) {
+        *self.anim.speed.write().unwrap() = speed;
+    }
+
+    pub fn should_exit(&self) -> bool {
+        let b = *self.should_exit.read().unwrap();
+        b
+    }
+
+    pub fn set_should_exit(&self, should_exit: bool) {
+        *self.should_exit.write().unwrap() = should_exit;
+    }
+
+    pub fn add_observer(&mut self, observer: Arc<dyn MazeObserver>) {
+        self.observers.push(observer);
+    }
+
+    // Algorithms should skip building events that are expensive to create if this is false
+    pub fn has_observers(&self) -> bool {
+        !self.observers.is_empty()
+    }
+
+    pub fn emit(&self, event: MazeEvent) -> Result<()> {
+        if self.observers.is_empty() {
+            return Ok(());
+        }
+
+        if self.should_exit() {
+            return Err(anyhow!("Terminated."));
+        }
+
+        for observer in &self.observers {
+            observer.on_event(&event)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn emit_all(&self, events: Vec<MazeEvent>) -> Result<()> {
+        for event in events {
+            self.emit(event)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn set_wall_toggles(&mut self, toggles: Arc<dyn WallToggles>) {
+        self.wall_toggles = Some(toggles);
+    }
+
+    pub fn take_wall_toggles(&self) -> Vec<Point> {
+        match &self.wall_toggles {
+            Some(toggles) => toggles.take_toggles(),
+            None => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use anyhow::anyhow;
+
+use crate::point::{point::Point, point_state::{PointState, VisualIndicator}};
+
+use super::{color::Color, consts::{Maze, VisualOverwrites}, math::points_to_dir, matrix::get_pos_between};
+
+// Colour of every point, visual overwrites are drawn on top of the maze
+pub fn render_maze(maze: &Maze, visual_overwrites: &VisualOverwrites) -> Vec<Color> {
+    maze.enumerate()
+        .map(|(p, point)| obtain_color(point, visual_overwrites.get(&p)))
+        .collect()
+}
+
+fn obtain_color(point: &PointState, overwrite: Option<&VisualIndicator>) -> Color {
+    if let Some(overwrite) = overwrite {
+        match *overwrite {
+            VisualIndicator::SolvePath => Color::from_rgb(255, 128, 0),
+            VisualIndicator::Searching => Color::from_rgb(0, 0, 255),
+            VisualIndicator::Match => Color::from_rgb(255, 0, 255),
+            VisualIndicator::End => Color::from_rgb(0, 255, 0),
+            VisualIndicator::Start => Color::from_rgb(255, 0, 0),
+            VisualIndicator::Waypoint => Color::from_rgb(255, 255, 0),
+            VisualIndicator::Alternative => Color::from_rgb(255, 200, 140),
+            VisualIndicator::Custom(c) => c
+        }
+    } else {
+        match point {
+            PointState::Passage => Color::from_rgb(255, 255, 255),
+            PointState::Wall => Color::from_rgb(0, 0, 0),
+        }
+    }
+}
+
+// Distinct colour for the nth leg of a route, hues are spread out using the golden ratio
+pub fn leg_color(index: usize) -> Color {
+    let hue = (index as f32 * 0.618_034).fract();
+    Color::from_hsv(hue, 0.85, 1.0)
+}
+
+// Marks every cell of the path and the passages in between them with the given indicator
+pub fn draw_path(path: &[Point], visual_overwrites: &mut VisualOverwrites, indicator: VisualIndicator) -> anyhow::Result<()> {
+    for pair in path.windows(2) {
+        let (p, n) = (pair[0], pair[1]);
+
+        let dir = points_to_dir(&n, &p).ok_or_else(|| anyhow!("{} and {} are not neighbouring cells", p, n))?;
+        let between = get_pos_between(&visual_overwrites.get_size(), &p, &dir)?
+            .ok_or_else(|| anyhow!("There is no point between {} and {}", p, n))?;
+        visual_overwrites.set_mult(&[p, between, n], indicator);
+    }
+
+    Ok(())
+}
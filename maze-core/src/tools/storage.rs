@@ -0,0 +1,82 @@
+use crate::point::{bit_grid::BitGrid, grid::Grid, point::Point, point_state::PointState, size::Size};
+
+use super::consts::Maze;
+
+// Anything that can hold the walls and passages of a maze. Generators and solvers are written
+// against this, so huge mazes can use a BitMaze while everything else keeps using a Maze.
+pub trait MazeStorage: Clone + Send + Sync {
+    fn filled(size: Size, state: PointState) -> Self;
+    fn get_size(&self) -> Size;
+
+    // Panics in debug builds if the point is outside of the maze
+    fn get_state(&self, p: &Point) -> PointState;
+    fn set_state(&mut self, p: &Point, state: PointState);
+
+    fn contains(&self, p: &Point) -> bool {
+        self.get_size().contains(p)
+    }
+
+    fn is_passage(&self, p: &Point) -> bool {
+        self.get_state(p) == PointState::Passage
+    }
+
+    fn to_maze(&self) -> Maze {
+        Grid::from_fn(self.get_size(), |p| self.get_state(&p))
+    }
+}
+
+impl MazeStorage for Maze {
+    fn filled(size: Size, state: PointState) -> Self {
+        Grid::new(size, state)
+    }
+
+    fn get_size(&self) -> Size {
+        Grid::get_size(self)
+    }
+
+    fn get_state(&self, p: &Point) -> PointState {
+        self[p]
+    }
+
+    fn set_state(&mut self, p: &Point, state: PointState) {
+        self[p] = state;
+    }
+
+    fn to_maze(&self) -> Maze {
+        self.clone()
+    }
+}
+
+// A set bit is a passage
+impl MazeStorage for BitGrid {
+    fn filled(size: Size, state: PointState) -> Self {
+        BitGrid::new(size, state == PointState::Passage)
+    }
+
+    fn get_size(&self) -> Size {
+        BitGrid::get_size(self)
+    }
+
+    fn get_state(&self, p: &Point) -> PointState {
+        if self.get(p) { PointState::Passage } else { PointState::Wall }
+    }
+
+    fn set_state(&mut self, p: &Point, state: PointState) {
+        self.set(p, state == PointState::Passage);
+    }
+
+    fn is_passage(&self, p: &Point) -> bool {
+        self.get(p)
+    }
+}
+
+pub fn to_bit_maze(maze: &Maze) -> BitGrid {
+    let mut bits = BitGrid::new(maze.get_size(), false);
+    for (p, state) in maze.enumerate() {
+        if *state == PointState::Passage {
+            bits.set(&p, true);
+        }
+    }
+
+    bits
+}
@@ -0,0 +1,223 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    analysis::validate::validate,
+    point::{grid::Grid, point::Point, point_state::PointState, size::Size},
+    solve::placement::Placement,
+};
+
+use super::consts::{Maze, MazeOptions};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextStyle {
+    // One character per cell, `#` for walls and spaces for passages
+    #[default]
+    Ascii,
+    // Thin walls drawn with box-drawing characters, cells are three characters wide
+    Unicode,
+}
+
+impl Display for TextStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextStyle::Ascii => write!(f, "ASCII"),
+            TextStyle::Unicode => write!(f, "Unicode"),
+        }
+    }
+}
+
+impl FromStr for TextStyle {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "ascii" => Ok(TextStyle::Ascii),
+            "unicode" | "box" => Ok(TextStyle::Unicode),
+            _ => Err(anyhow!("Unknown text style {}", s)),
+        }
+    }
+}
+
+// What a single passage cell shows besides being a passage
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mark {
+    None,
+    Start,
+    End,
+    Path,
+}
+
+impl Mark {
+    fn get_char(&self) -> char {
+        match self {
+            Mark::None => ' ',
+            Mark::Start => 'S',
+            Mark::End => 'E',
+            Mark::Path => '.',
+        }
+    }
+
+    fn from_char(c: char) -> Option<Mark> {
+        match c {
+            ' ' => Some(Mark::None),
+            'S' => Some(Mark::Start),
+            'E' => Some(Mark::End),
+            '.' => Some(Mark::Path),
+            _ => None,
+        }
+    }
+}
+
+// Every leg of the solution is written as `.` including the passages between cells, start and end become `S` and `E`
+pub fn maze_to_text(maze: &Maze, legs: &[Vec<Point>], markers: Option<(Point, Point)>, style: TextStyle) -> Result<String> {
+    let size = maze.get_size();
+    let mut marks = Grid::new(size, Mark::None);
+    for leg in legs {
+        for pair in leg.windows(2) {
+            let (p, n) = (pair[0], pair[1]);
+            let between = Point { x: (p.x + n.x) / 2, y: (p.y + n.y) / 2 };
+            for e in [p, between, n] {
+                marks[e] = Mark::Path;
+            }
+        }
+    }
+
+    if let Some((start, end)) = markers {
+        marks[start] = Mark::Start;
+        marks[end] = Mark::End;
+    }
+
+    let is_wall = |x: usize, y: usize| maze[Point { x, y }] == PointState::Wall;
+    let mut out = String::new();
+    for y in 0..size.height {
+        for x in 0..size.width {
+            let mark = marks[Point { x, y }].get_char();
+            match style {
+                TextStyle::Ascii => out.push(if is_wall(x, y) { '#' } else { mark }),
+                TextStyle::Unicode => out.push_str(&unicode_cell(size, x, y, mark, &is_wall)),
+            }
+        }
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+fn unicode_cell(size: Size, x: usize, y: usize, mark: char, is_wall: &dyn Fn(usize, usize) -> bool) -> String {
+    let wide = |c: char| format!("{}{}{}", c, c, c);
+    let wall = is_wall(x, y);
+    match (x.is_multiple_of(2), y.is_multiple_of(2)) {
+        // Corner posts connect to the walls around them
+        (true, true) if wall => {
+            let up = y > 0 && is_wall(x, y - 1);
+            let down = y + 1 < size.height && is_wall(x, y + 1);
+            let left = x > 0 && is_wall(x - 1, y);
+            let right = x + 1 < size.width && is_wall(x + 1, y);
+            box_char(up, down, left, right).to_string()
+        }
+        (true, false) if wall => "│".to_string(),
+        (false, true) if wall => wide('─'),
+        (false, false) if wall => wide('█'),
+        (true, _) => mark.to_string(),
+        (false, _) => format!(" {} ", mark),
+    }
+}
+
+fn box_char(up: bool, down: bool, left: bool, right: bool) -> char {
+    match (up, down, left, right) {
+        (false, false, false, false) => '·',
+        (true, false, false, false) => '╵',
+        (false, true, false, false) => '╷',
+        (false, false, true, false) => '╴',
+        (false, false, false, true) => '╶',
+        (true, true, false, false) => '│',
+        (false, false, true, true) => '─',
+        (false, true, false, true) => '┌',
+        (false, true, true, false) => '┐',
+        (true, false, false, true) => '└',
+        (true, false, true, false) => '┘',
+        (true, true, false, true) => '├',
+        (true, true, true, false) => '┤',
+        (false, true, true, true) => '┬',
+        (true, false, true, true) => '┴',
+        (true, true, true, true) => '┼',
+    }
+}
+
+fn is_box_drawing(c: char) -> bool {
+    ('\u{2500}'..='\u{259f}').contains(&c) || c == '·'
+}
+
+// Reads both styles, which one is used is detected by looking for box-drawing characters.
+// Returns the `.` cells as the solution, `S` and `E` become a custom placement, a missing one falls back to its corner.
+pub fn text_to_maze(text: &str, validate_maze: bool) -> Result<(Maze, MazeOptions, Vec<Point>)> {
+    let lines = text
+        .lines()
+        .map(|e| e.trim_end_matches('\r'))
+        .skip_while(|e| e.trim().is_empty())
+        .collect::<Vec<_>>();
+    let lines = &lines[..lines.iter().rposition(|e| !e.trim().is_empty()).map_or(0, |e| e + 1)];
+
+    let unicode = lines.iter().any(|e| e.chars().any(is_box_drawing));
+    // The right border is never blank, so the longest row tells the width
+    let columns = lines.iter().map(|e| e.chars().count()).max().unwrap_or(0);
+    let width = if unicode { columns.saturating_sub(1) / 4 * 2 + 1 } else { columns };
+    let size = Size::new(width, lines.len());
+    if !size.is_valid() {
+        return Err(anyhow!("A maze needs an odd amount of rows and columns, found {}", size));
+    }
+
+    let mut maze = Grid::new(size, PointState::Wall);
+    let mut start = None;
+    let mut end = None;
+    let mut solution = Vec::new();
+    for (y, line) in lines.iter().enumerate() {
+        let chars = line.chars().collect::<Vec<_>>();
+        for x in 0..size.width {
+            // Editors like to strip trailing spaces, so missing characters are passages
+            let c = if unicode {
+                let column = x / 2 * 4 + if x.is_multiple_of(2) { 0 } else { 2 };
+                chars.get(column).copied().unwrap_or(' ')
+            } else {
+                chars.get(x).copied().unwrap_or(' ')
+            };
+
+            let mark = Mark::from_char(c);
+            if mark.is_none() {
+                if !unicode && c != '#' {
+                    return Err(anyhow!("Unknown character '{}' at {},{}", c, x, y));
+                }
+                continue;
+            }
+
+            let p = Point { x, y };
+            maze[p] = PointState::Passage;
+            match mark.unwrap() {
+                Mark::Start if start.is_some() => return Err(anyhow!("Found a second start at {}", p)),
+                Mark::End if end.is_some() => return Err(anyhow!("Found a second end at {}", p)),
+                Mark::Start => start = Some(p),
+                Mark::End => end = Some(p),
+                Mark::Path => solution.push(p),
+                Mark::None => {}
+            }
+        }
+    }
+
+    let mut opt = MazeOptions::rect(size.width, size.height, u64::MAX, 0);
+    opt.generator = None;
+    opt.validate = validate_maze;
+    if let Some(placement) = Placement::from_markers(&maze, start, end) {
+        opt.placement = placement;
+    }
+
+    if validate_maze {
+        let validation = validate(&maze)?;
+        if !validation.is_well_formed_with(opt.placement.markers(size)) {
+            return Err(anyhow!("Text is not a valid maze. {}", validation));
+        }
+    }
+
+    Ok((maze, opt, solution))
+}
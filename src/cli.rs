@@ -0,0 +1,280 @@
+use std::{fs, path::{Path, PathBuf}, sync::Arc};
+
+use anyhow::{anyhow, Result};
+use clap::{Args, Parser, Subcommand};
+
+use maze_core::{
+    events::recorder::Recorder,
+    export::{pdf::{mazes_to_pdf, PageSize, PdfOptions}, segments::WallStyle},
+    generators::generate::Generator,
+    point::{point::Point, size::Size},
+    run::{load_maze, MazeRun},
+    solve::solve::SolveAlgorithm,
+    tools::{animation::RecordOptions, consts::{BitMaze, MazeOptions}, options::{AnimOptions, MazeData}},
+};
+
+#[derive(Parser)]
+#[command(name = "maze-solver", about = "Generates and solves mazes. Opens the GUI if no command is given.")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generates a maze and saves it without a solution
+    Generate {
+        #[command(flatten)]
+        maze: MazeArgs,
+        #[command(flatten)]
+        record: RecordArgs,
+        /// Keeps the maze in one bit per point, for mazes too big for the memory otherwise
+        #[arg(long)]
+        bit_packed: bool,
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Generates or loads a maze and saves it with the solution drawn in
+    Solve {
+        #[command(flatten)]
+        maze: MazeArgs,
+        #[command(flatten)]
+        record: RecordArgs,
+        #[arg(short, long, default_value = "a-star")]
+        algorithm: SolveAlgorithm,
+        /// Maze to solve instead of generating a new one
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Keeps the maze in one bit per point and leaves the searched cells out of the image, for huge mazes
+        #[arg(long, conflicts_with = "input")]
+        bit_packed: bool,
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Writes the metrics of a generated or loaded maze as JSON
+    Analyze {
+        #[command(flatten)]
+        maze: MazeArgs,
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Generates a printable PDF with one page per maze and the solutions at the end
+    Pdf {
+        #[command(flatten)]
+        maze: MazeArgs,
+        /// Amount of mazes, the seed is increased by one for every maze
+        #[arg(short, long, default_value_t = 1)]
+        count: usize,
+        #[arg(long, default_value = "a4")]
+        page_size: PageSize,
+        #[arg(long, default_value = "Maze")]
+        title: String,
+        /// Thin walls (lines) or filled cells (blocks)
+        #[arg(long, default_value = "lines")]
+        style: WallStyle,
+        /// Leaves out the answer pages
+        #[arg(long)]
+        no_solutions: bool,
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Loads a maze and saves it in the format given by the extension of the output
+    Convert {
+        #[arg(short, long)]
+        input: PathBuf,
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+}
+
+#[derive(Args)]
+pub struct MazeArgs {
+    /// Rounded up to odd numbers, e.g. 51 or 41x21
+    #[arg(short, long, default_value_t = Size::square(51), value_parser = parse_size)]
+    size: Size,
+    /// Random if not given
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Chance in percent that walls between two passages are removed
+    #[arg(short, long, default_value_t = 0)]
+    decimate: usize,
+    /// hunt-and-kill, hunt-and-kill-scan or parallel. hunt-and-kill-scan for bit packed mazes, hunt-and-kill otherwise
+    #[arg(short, long)]
+    generator: Option<Generator>,
+    /// Threads of the parallel generator, one per core if not given
+    #[arg(short, long)]
+    threads: Option<usize>,
+}
+
+#[derive(Args)]
+pub struct RecordArgs {
+    /// Also saves the run as an animated .gif or .apng
+    #[arg(long)]
+    record: Option<PathBuf>,
+    /// Only every nth step becomes a frame of the recording
+    #[arg(long, default_value_t = 10)]
+    frame_skip: usize,
+    /// Pixels per cell in the recording
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+    /// Time in ms the last frame of the recording is shown
+    #[arg(long, default_value_t = 2000)]
+    hold: u64,
+}
+
+impl RecordArgs {
+    fn to_recorder(&self) -> Option<Arc<Recorder>> {
+        self.record.as_ref()?;
+
+        let options = RecordOptions {
+            frame_skip: self.frame_skip,
+            scale: self.scale,
+            hold: self.hold,
+            ..Default::default()
+        };
+        Some(Arc::new(Recorder::new(options)))
+    }
+
+    fn save(&self, recorder: Option<Arc<Recorder>>) -> Result<()> {
+        let (Some(path), Some(recorder)) = (&self.record, recorder) else {
+            return Ok(());
+        };
+
+        recorder.save(&path.to_string_lossy())?;
+        println!("Recorded {} frames to {}", recorder.frame_count(), path.display());
+
+        Ok(())
+    }
+}
+
+impl MazeArgs {
+    fn to_options(&self, bit_packed: bool) -> MazeOptions {
+        let seed = self.seed.unwrap_or_else(|| {
+            let seed = rand::random();
+            println!("Using seed {}", seed);
+            seed
+        });
+        let mut opt = MazeOptions::rect(self.size.width, self.size.height, seed, self.decimate);
+        // The scan doesn't need any memory besides the maze
+        let default = if bit_packed { Generator::HuntAndKillScan } else { Generator::HuntAndKill };
+        opt.generator = Some(self.generator.unwrap_or(default));
+        opt.trace_search = !bit_packed;
+        if let Some(threads) = self.threads {
+            opt.threads = threads.max(1);
+        }
+
+        opt
+    }
+}
+
+pub fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Generate { maze, record, bit_packed, output } => {
+            let recorder = record.to_recorder();
+            let data = headless(&maze.to_options(bit_packed), recorder.clone());
+            if bit_packed {
+                MazeRun::<BitMaze>::generate(&data, SolveAlgorithm::None)?.save(&output.to_string_lossy())?;
+                println!("Saved to {}", output.display());
+            } else {
+                save(&MazeRun::generate(&data, SolveAlgorithm::None)?, &output)?;
+            }
+
+            record.save(recorder)
+        }
+        Command::Solve { maze, record, algorithm, input, bit_packed, output } => {
+            let recorder = record.to_recorder();
+            if bit_packed {
+                let data = headless(&maze.to_options(true), recorder.clone());
+                let run = MazeRun::<BitMaze>::generate(&data, algorithm)?;
+                print_steps(&run.legs);
+
+                run.save(&output.to_string_lossy())?;
+                println!("Saved to {}", output.display());
+            } else {
+                let run = create_run(&maze, input.as_ref(), algorithm, recorder.clone(), false)?;
+                print_steps(&run.legs);
+
+                save(&run, &output)?;
+            }
+
+            record.save(recorder)
+        }
+        Command::Analyze { maze, input, output } => {
+            let run = create_run(&maze, input.as_ref(), SolveAlgorithm::None, None, true)?;
+            fs::write(&output, run.metrics.unwrap_or_default().to_json()?)?;
+            println!("Saved to {}", output.display());
+
+            Ok(())
+        }
+        Command::Pdf { maze, count, page_size, title, style, no_solutions, output } => {
+            let first = maze.to_options(false);
+            let mut runs = Vec::new();
+            for i in 0..count.max(1) {
+                let mut opt = MazeOptions::rect(first.size.width, first.size.height, first.seed.wrapping_add(i as u64), first.decimate);
+                opt.generator = first.generator;
+                opt.threads = first.threads;
+                runs.push(MazeRun::generate(&headless(&opt, None), SolveAlgorithm::AStar)?);
+            }
+
+            let options = PdfOptions { page_size, title, style, solutions: !no_solutions, ..Default::default() };
+            fs::write(&output, mazes_to_pdf(&runs, &options)?)?;
+            println!("Saved {} mazes to {}", runs.len(), output.display());
+
+            Ok(())
+        }
+        Command::Convert { input, output } => {
+            let (maze, opt) = load_maze(&input.to_string_lossy())?;
+            let data = headless(&opt, None);
+            let run = MazeRun::load(&data, maze, SolveAlgorithm::None)?;
+            save(&run, &output)
+        }
+    }
+}
+
+fn create_run(args: &MazeArgs, input: Option<&PathBuf>, algorithm: SolveAlgorithm, recorder: Option<Arc<Recorder>>, analyze: bool) -> Result<MazeRun> {
+    let Some(input) = input else {
+        let mut opt = args.to_options(false);
+        opt.analyze = analyze;
+        return MazeRun::generate(&headless(&opt, recorder), algorithm);
+    };
+
+    let (maze, mut opt) = load_maze(&input.to_string_lossy())?;
+    opt.analyze = analyze;
+    let data = headless(&opt, recorder);
+    MazeRun::load(&data, maze, algorithm)
+}
+
+// Sizes are rounded up to odd ones like everywhere else, a maze needs at least two cells for its start and end
+fn parse_size(s: &str) -> Result<Size> {
+    let size = s.parse::<Size>()?.to_odd();
+    let (cells_x, cells_y) = size.cells();
+    if !size.is_valid() || cells_x * cells_y < 2 {
+        return Err(anyhow!("{} is too small, a maze needs at least two cells, e.g. 5x3", size));
+    }
+
+    Ok(size)
+}
+
+// Nothing is drawn until the result is saved, unless the run is recorded
+fn headless(opt: &MazeOptions, recorder: Option<Arc<Recorder>>) -> MazeData {
+    let mut data = MazeData::headless(opt, &AnimOptions::new(false, recorder.is_some(), 1.0));
+    if let Some(recorder) = recorder {
+        data.add_observer(recorder);
+    }
+
+    data
+}
+
+fn print_steps(legs: &[Vec<Point>]) {
+    let steps: usize = legs.iter().map(|e| e.len().saturating_sub(1)).sum();
+    println!("Solution has {} steps", steps);
+}
+
+fn save(run: &MazeRun, output: &Path) -> Result<()> {
+    run.save(&output.to_string_lossy())?;
+    println!("Saved to {}", output.display());
+
+    Ok(())
+}
@@ -1,5 +0,0 @@
-pub mod generate;
-mod hunt_and_kill;
-pub use hunt_and_kill::hunt_and_kill;
-pub mod tools;
-pub mod decimate;
\ No newline at end of file
@@ -0,0 +1,870 @@
+use std::{path::PathBuf, sync::Arc};
+
+use eframe::{App, Theme};
+use egui::*;
+use im_native_dialog::ImNativeFileDialog;
+use maze_core::{
+    chunks::ChunkOptions,
+    events::{event::MazeObserver, log::LogObserver, recorder::Recorder},
+    generators::generate::Generator,
+    point::{point::Point, size::Size},
+    solve::{placement::Placement, solve::SolveAlgorithm},
+    tools::{
+        animation::RecordOptions,
+        consts::{default_threads, MazeOptions},
+        math::{numb_to_vec2, vec2_to_numb},
+        color::Color,
+        options::{AnimOptions, MazeData},
+    },
+};
+
+use super::{canvas::AnimatedCanvas, endless::EndlessViewer, manager::MazeThread};
+
+const ICON: &[u8; 324] = include_bytes!("../assets/icon.png");
+pub fn run() -> Result<(), eframe::Error> {
+    let options = eframe::NativeOptions {
+        icon_data: Some(eframe::IconData {
+            rgba: ICON.to_vec(),
+            width: 32,
+            height: 32,
+        }),
+        ..Default::default()
+    };
+    eframe::run_native(
+        "Maze Solver",
+        options,
+        Box::new(|cc| Box::new(MyApp::new(&cc.egui_ctx))),
+    )
+}
+
+struct MyApp {
+    maze_img: Option<TextureHandle>,
+    curr: Option<MazeThread>,
+
+    seed_random: bool,
+    seed: u64,
+    seed_text: String,
+
+    solve_algorithm: SolveAlgorithm,
+    generator: Generator,
+    threads: usize,
+    size: Size,
+    size_text: String,
+
+    show_animation: bool,
+
+    speed: f64,
+    speed_text: String,
+
+    show_debug: bool,
+    validate: bool,
+    use_graph: bool,
+
+    record: bool,
+    record_opt: RecordOptions,
+
+    // Maze file that is solved instead of generating a new one
+    loaded: Option<PathBuf>,
+    open_path: PathBuf,
+    open_file_dialog: ImNativeFileDialog<Option<PathBuf>>,
+
+    save_path: PathBuf,
+    save_file_dialog: ImNativeFileDialog<Option<PathBuf>>,
+
+    metrics_path: PathBuf,
+    metrics_file_dialog: ImNativeFileDialog<Option<PathBuf>>,
+
+    decimate: usize,
+
+    placement: Placement,
+    custom_start: Point,
+    custom_end: Point,
+
+    waypoints: usize,
+    agents: usize,
+
+    // Shows an endless maze made of tiles instead of a single one
+    endless: bool,
+    chunk_options: ChunkOptions,
+    zoom: usize,
+    endless_viewer: Option<EndlessViewer>,
+}
+
+impl MyApp {
+    fn new(ctx: &Context) -> Self {
+        let rand_seed = rand::random();
+
+        let size = Size::square(50);
+        let mut e = Self {
+            maze_img: None,
+            seed: rand_seed,
+            seed_text: rand_seed.to_string(),
+            seed_random: true,
+            curr: None,
+            solve_algorithm: SolveAlgorithm::AStar,
+            generator: Generator::HuntAndKill,
+            threads: default_threads(),
+
+            size,
+            size_text: size.to_string(),
+
+            show_animation: true,
+
+            speed: 0.975,
+            speed_text: "1.0".to_string(),
+            show_debug: true,
+            validate: false,
+            use_graph: false,
+
+            record: false,
+            record_opt: RecordOptions::default(),
+
+            loaded: None,
+            open_path: Default::default(),
+            open_file_dialog: Default::default(),
+
+            save_path: Default::default(),
+            save_file_dialog: Default::default(),
+            metrics_path: Default::default(),
+            metrics_file_dialog: Default::default(),
+            decimate: 2,
+
+            placement: Placement::Corners,
+            custom_start: Point { x: 1, y: 1 },
+            custom_end: Point { x: size.width - 2, y: size.height - 2 },
+
+            waypoints: 5,
+            agents: 4,
+
+            endless: false,
+            chunk_options: ChunkOptions::default(),
+            zoom: 8,
+            endless_viewer: None,
+        };
+
+        e.curr = Some(e.start_thread(ctx));
+        e
+    }
+
+    fn start_thread(&self, ctx: &Context) -> MazeThread {
+        let mut maze_opt = MazeOptions::rect(self.size.width, self.size.height, self.seed, self.decimate);
+        maze_opt.placement = self.placement;
+        maze_opt.waypoints = self.waypoints;
+        maze_opt.agents = self.agents;
+        maze_opt.validate = self.validate;
+        maze_opt.use_graph = self.use_graph;
+        maze_opt.analyze = true;
+        maze_opt.generator = Some(self.generator);
+        maze_opt.threads = self.threads;
+
+        let anim_opt = AnimOptions::new(
+            self.show_debug,
+            self.show_animation,
+            Self::get_speed_limited(self.size, self.speed),
+        );
+
+        let canvas = Arc::new(AnimatedCanvas::new(ctx, &anim_opt));
+        let mut observers: Vec<Arc<dyn MazeObserver>> = vec![canvas.clone()];
+        if self.show_debug {
+            observers.push(Arc::new(LogObserver { print_paths: true }));
+        }
+
+        let recorder = self.record.then(|| Arc::new(Recorder::new(self.record_opt)));
+        if let Some(recorder) = &recorder {
+            observers.push(recorder.clone());
+        }
+
+        let data = MazeData::new(&observers, &maze_opt, &anim_opt);
+        let path = self.loaded.as_ref().map(|e| e.to_string_lossy().to_string());
+        MazeThread::new(&data, &canvas, recorder, self.solve_algorithm, path)
+    }
+}
+
+impl MyApp {
+    fn add_image(&mut self, ctx: &Context, ui: &mut Ui) {
+        let left = ui.available_size_before_wrap();
+        if self.curr.is_none() {
+            return;
+        }
+
+        let thread = self.curr.as_ref().unwrap();
+        let maze_dim = thread.get_options().size;
+        if maze_dim.is_empty() || left.min_elem() < 1.0 {
+            return;
+        }
+
+        // Largest image with the aspect ratio of the maze that fits into the space that is left
+        let scale = (left.x as f64 / maze_dim.width as f64).min(left.y as f64 / maze_dim.height as f64);
+        let img_width = ((maze_dim.width as f64 * scale) as usize).max(1);
+        let img_height = ((maze_dim.height as f64 * scale) as usize).max(1);
+
+        let mut img = ColorImage::new([img_width, img_height], Color32::BLACK);
+
+        let texture = self.maze_img.get_or_insert_with(|| {
+            ctx.load_texture("maze-texture", ColorImage::example(), Default::default())
+        });
+
+        let maze_pixels = thread.get_canvas().get_canvas().render();
+        if maze_pixels.len() != maze_dim.len() {
+            return;
+        }
+
+        for (pos, pixel) in img.pixels.iter_mut().enumerate() {
+            let Point { x, y } = numb_to_vec2(pos, img_width);
+            let maze_x = x * maze_dim.width / img_width;
+            let maze_y = y * maze_dim.height / img_height;
+
+            *pixel = to_color32(&maze_pixels[vec2_to_numb(maze_x, maze_y, maze_dim.width)]);
+        }
+
+        texture.set(img, Default::default());
+        let res = ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
+            ui.add(Image::new(texture.id(), texture.size_vec2()).sense(Sense::click()))
+        }).inner;
+
+        // Clicking the maze while D* Lite is running toggles walls the agent has to plan around
+        let status = thread.get_status();
+        let is_solving = thread.get_canvas().get_canvas().get_progress() == 1.0 && !status.is_done();
+        if let Some(pos) = res.interact_pointer_pos() {
+            if res.clicked() && is_solving && thread.get_algorithm() == SolveAlgorithm::DStarLite {
+                let local = pos - res.rect.min;
+                let x = (local.x as f64 / img_width as f64 * maze_dim.width as f64) as usize;
+                let y = (local.y as f64 / img_height as f64 * maze_dim.height as f64) as usize;
+
+                status.push_wall_toggle(Point { x, y });
+            }
+        }
+    }
+
+    fn add_endless_options(&mut self, ui: &mut Ui) {
+        ui.checkbox(&mut self.endless, "Endless?")
+            .on_hover_text("Drag the maze or use the arrow keys to move around, tiles are generated when they come into view");
+        if !self.endless {
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Tile size:");
+            ui.add(DragValue::new(&mut self.chunk_options.tile_size).clamp_range(5..=201));
+            ui.label("Doors:");
+            let max_doors = self.chunk_options.tile_size / 2;
+            ui.add(DragValue::new(&mut self.chunk_options.doors).clamp_range(1..=max_doors));
+            ui.label("Zoom:");
+            ui.add(Slider::new(&mut self.zoom, 1..=32));
+        });
+
+        // Borders between tiles are shared, so tiles always have an odd size
+        if self.chunk_options.tile_size.is_multiple_of(2) {
+            self.chunk_options.tile_size += 1;
+        }
+        self.chunk_options.doors = self.chunk_options.doors.clamp(1, self.chunk_options.tile_size / 2);
+        self.chunk_options.decimate = self.decimate;
+    }
+
+    fn add_endless_view(&mut self, ctx: &Context, ui: &mut Ui) {
+        let is_current = self.endless_viewer.as_ref().is_some_and(|e| e.is_for(self.seed, &self.chunk_options));
+        if !is_current {
+            match EndlessViewer::new(ctx, self.seed, self.chunk_options) {
+                Ok(viewer) => self.endless_viewer = Some(viewer),
+                Err(e) => {
+                    ui.label(format!("Invalid tiles: {}", e));
+                    return;
+                }
+            }
+        }
+
+        let viewer = self.endless_viewer.as_mut().unwrap();
+        let center = viewer.get_center_tile(ui.available_size_before_wrap(), self.zoom);
+        ui.label(format!("Tile {}, {} tiles loaded", center, viewer.get_world().loaded_tiles()));
+        if let Err(e) = viewer.show(ctx, ui, self.zoom) {
+            eprintln!("Could not show endless maze: {}", e);
+        }
+    }
+
+    fn regenerate_seed(&mut self) {
+        let r = rand::random();
+        self.seed = r;
+        self.seed_text = r.to_string();
+    }
+
+    fn add_seed_selector(&mut self, ui: &mut Ui, frame: &mut eframe::Frame) {
+        ui.horizontal(|ui| {
+            let checkbox = ui.checkbox(&mut self.seed_random, "Random?");
+            if checkbox.changed() && self.seed_random {
+                self.regenerate_seed()
+            }
+
+            let seed_label = ui.label("Seed: ");
+            ui.add_enabled_ui(!self.seed_random, |ui| {
+                if self.seed_random {
+                    self.seed_text = self.seed.to_string();
+                }
+
+                let valid_seed = self.seed_text.parse::<u64>().is_ok();
+                let theme = frame.info().system_theme.unwrap_or(Theme::Dark);
+
+                let mut text_color = if theme == Theme::Dark {
+                    Color32::WHITE
+                } else {
+                    Color32::BLACK
+                };
+                if !valid_seed {
+                    text_color = if theme == Theme::Dark {
+                        Color32::LIGHT_RED
+                    } else {
+                        Color32::DARK_RED
+                    };
+                }
+
+                let res = TextEdit::singleline(&mut self.seed_text)
+                    .text_color(text_color)
+                    .ui(ui)
+                    .labelled_by(seed_label.id);
+                if res.changed() {
+                    if let Ok(seed) = self.seed_text.parse::<u64>() {
+                        self.seed = seed;
+                    }
+                }
+            });
+        });
+    }
+
+    fn add_size_selector(&mut self, ui: &mut Ui, frame: &mut eframe::Frame) {
+        ui.horizontal(|ui| {
+            let size_label = ui.label("Size: ").on_hover_text("A single number for square mazes or width x height, e.g. 81x41");
+            let valid_size = self.size_text.parse::<Size>().is_ok();
+            let theme = frame.info().system_theme.unwrap_or(Theme::Dark);
+
+            let mut text_color = if theme == Theme::Dark {
+                Color32::WHITE
+            } else {
+                Color32::BLACK
+            };
+            if !valid_size {
+                text_color = if theme == Theme::Dark {
+                    Color32::LIGHT_RED
+                } else {
+                    Color32::DARK_RED
+                };
+            }
+
+            let res = TextEdit::singleline(&mut self.size_text)
+                .text_color(text_color)
+                .ui(ui)
+                .labelled_by(size_label.id);
+            if res.changed() {
+                if let Ok(size) = self.size_text.parse::<Size>() {
+                    self.size = size;
+                }
+            }
+        });
+    }
+
+    fn add_speed_selector(&mut self, ui: &mut Ui, frame: &mut eframe::Frame) {
+        ui.horizontal(|ui| {
+            let speed_label = ui.label("Speed: ");
+            let valid_speed = self.speed_text.parse::<f64>().is_ok();
+            let theme = frame.info().system_theme.unwrap_or(Theme::Dark);
+
+            let mut text_color = if theme == Theme::Dark {
+                Color32::WHITE
+            } else {
+                Color32::BLACK
+            };
+            if !valid_speed {
+                text_color = if theme == Theme::Dark {
+                    Color32::LIGHT_RED
+                } else {
+                    Color32::DARK_RED
+                };
+            }
+
+            let res = TextEdit::singleline(&mut self.speed_text)
+                .text_color(text_color)
+                .ui(ui)
+                .labelled_by(speed_label.id);
+            if res.changed() {
+                if let Ok(speed) = self.speed_text.parse::<f64>() {
+                    self.speed = speed;
+                    if let Some(c) = &mut self.curr {
+                        let size = c.get_data().get_opt().size;
+
+                        c.get_mut_data()
+                            .set_speed_anim(Self::get_speed_limited(size, self.speed));
+                    }
+                }
+            }
+        });
+    }
+
+    fn get_speed_limited(size: Size, speed: f64) -> f64 {
+        if size.max() > 1000 {
+            size.len() as f64 * 0.0025
+        } else {
+            speed
+        }
+    }
+
+    fn add_show_animation(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            let size_label = ui.label("Show Animation: ");
+            let res = ui
+                .checkbox(&mut self.show_animation, "Show Animation")
+                .labelled_by(size_label.id);
+
+            if let Some(c) = self.curr.as_mut().filter(|_| res.changed()) {
+                c.get_mut_data().set_show_anim(self.show_animation);
+            }
+        });
+    }
+
+    fn add_record_options(&mut self, ui: &mut Ui) {
+        ui.checkbox(&mut self.record, "Record animation?");
+        if !self.record {
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Frame skip:");
+            ui.add(DragValue::new(&mut self.record_opt.frame_skip).clamp_range(1..=1000));
+            ui.label("Scale:");
+            ui.add(DragValue::new(&mut self.record_opt.scale).clamp_range(1..=16));
+            ui.label("Hold (ms):");
+            ui.add(DragValue::new(&mut self.record_opt.hold).clamp_range(0..=60000));
+        });
+    }
+
+    fn add_decimate_slider(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Decimate (0-100): ");
+            ui.add(Slider::new(&mut self.decimate, 0..=100));
+        });
+    }
+
+    fn add_generator_selector(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Generator:");
+            ComboBox::from_id_source("generator")
+            .selected_text(format!("{}", self.generator))
+            .show_ui(ui, |ui| {
+                for g in Generator::all() {
+                    ui.selectable_value(&mut self.generator, g, format!("{}", g));
+                }
+            });
+
+            if self.generator == Generator::Parallel {
+                ui.label("Threads:");
+                ui.add(DragValue::new(&mut self.threads).clamp_range(1..=256))
+                    .on_hover_text("The same seed only creates the same maze with the same amount of threads");
+            }
+        });
+    }
+
+    fn add_solve_algorithm(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Solving Algorithm:");
+            ComboBox::from_label("")
+            .selected_text(format!("{}", self.solve_algorithm))
+            .show_ui(ui, |ui| {
+                for s in SolveAlgorithm::all() {
+                    ui.selectable_value(&mut self.solve_algorithm, s, format!("{}", s));
+                }
+            });
+        });
+
+        if self.solve_algorithm == SolveAlgorithm::AStar {
+            ui.checkbox(&mut self.use_graph, "Solve on junction graph?");
+        }
+
+        if self.solve_algorithm == SolveAlgorithm::Waypoints {
+            ui.horizontal(|ui| {
+                ui.label("Waypoints: ");
+                ui.add(Slider::new(&mut self.waypoints, 1..=20));
+            });
+        }
+
+        if self.solve_algorithm == SolveAlgorithm::MultiAgent {
+            ui.horizontal(|ui| {
+                ui.label("Agents: ");
+                ui.add(Slider::new(&mut self.agents, 1..=10));
+            });
+        }
+
+    }
+
+    fn add_placement_selector(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Start / End:");
+            ComboBox::from_id_source("placement")
+            .selected_text(format!("{}", self.placement))
+            .show_ui(ui, |ui| {
+                for p in Placement::all() {
+                    let selected = std::mem::discriminant(&self.placement) == std::mem::discriminant(&p);
+                    if ui.selectable_label(selected, format!("{}", p)).clicked() {
+                        self.placement = p;
+                    }
+                }
+            });
+        });
+
+        if let Placement::Custom(..) = self.placement {
+            let max_x = self.size.width.max(1) - 1;
+            let max_y = self.size.height.max(1) - 1;
+            ui.horizontal(|ui| {
+                ui.label("Start:");
+                ui.add(DragValue::new(&mut self.custom_start.x).clamp_range(0..=max_x));
+                ui.add(DragValue::new(&mut self.custom_start.y).clamp_range(0..=max_y));
+                ui.label("End:");
+                ui.add(DragValue::new(&mut self.custom_end.x).clamp_range(0..=max_x));
+                ui.add(DragValue::new(&mut self.custom_end.y).clamp_range(0..=max_y));
+            });
+
+            self.placement = Placement::Custom(self.custom_start, self.custom_end);
+        }
+    }
+
+    fn add_open_button(&mut self, ui: &mut Ui, ctx: &Context) {
+        if let Some(result) = self.open_file_dialog.check() {
+            match result {
+                Ok(Some(path)) => {
+                    self.open_path = path.clone();
+                    self.loaded = Some(path);
+
+                    // A thread that is done waiting for saves is restarted by the generate button once it exited
+                    if let Some(c) = self.curr.as_ref().filter(|e| !e.is_finished()) {
+                        c.terminate();
+                    } else {
+                        self.curr = Some(self.start_thread(ctx));
+                    }
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    eprintln!("Error selecting maze path: {}", error)
+                }
+            }
+        }
+
+        let is_idle = self.curr.as_ref().is_none_or(|e| e.get_status().is_done() || e.is_finished());
+        ui.add_enabled_ui(!self.open_file_dialog.is_open() && is_idle, |ui| {
+            if ui.button("Open Maze").clicked() {
+                let location = self
+                    .open_path
+                    .parent()
+                    .map(|location| location.to_path_buf());
+
+                let temp = ctx.clone();
+                self.open_file_dialog
+                    .with_callback(move |_| temp.request_repaint())
+                    .show(|sender, dialog, callback| {
+                        let dialog = match &location {
+                            Some(location) => dialog.set_location(location),
+                            None => dialog,
+                        };
+                        let result = dialog
+                            .add_filter("Maze", &["png", "maze", "json", "txt", "utf8", "bmp", "jpg", "jpeg"])
+                            .add_filter("Image", &["png", "bmp", "jpg", "jpeg"])
+                            .add_filter("Binary Maze", &["maze"])
+                            .add_filter("JSON Maze", &["json"])
+                            .add_filter("Text", &["txt", "utf8"])
+                            .show_open_single_file();
+                        callback(&result);
+                        sender
+                            .send(result)
+                            .expect("error sending show_open_single_file result to ui");
+                        drop(location)
+                    })
+                    .expect("Unable to open file_path dialog");
+            }
+        });
+
+        if self.loaded.is_some() {
+            let name = self.loaded.as_ref().unwrap().file_name().unwrap_or_default().to_string_lossy().to_string();
+            ui.horizontal(|ui| {
+                ui.label(format!("Loaded: {}", name));
+                if ui.button("Close").clicked() {
+                    self.loaded = None;
+                }
+            });
+        }
+    }
+
+    fn add_save_button(&mut self, ui: &mut Ui, ctx: &Context) {
+        if let Some(result) = self.save_file_dialog.check() {
+            match result {
+                Ok(Some(path)) => {
+                    self.save_path = path;
+                    if self.curr.is_none() {
+                        eprintln!("Tried to save without curr");
+                    }
+                    let thread = self.curr.as_ref().unwrap();
+                    thread
+                        .get_status()
+                        .set_requested(self.save_path.to_string_lossy().to_string());
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    eprintln!("Error selecting xplane_path: {}", error)
+                }
+            }
+        }
+
+        ui.add_enabled_ui(
+            !self.save_file_dialog.is_open()
+                && self.curr.is_some()
+                && self.curr.as_ref().unwrap().get_status().is_done(),
+            |ui| {
+                if ui.button("Save Maze").clicked() {
+                    let location = self
+                        .save_path
+                        .parent()
+                        .map(|location| location.to_path_buf());
+
+                    let temp = ctx.clone();
+                    self.save_file_dialog
+                        .with_callback(move |_| temp.request_repaint())
+                        .show(|sender, dialog, callback| {
+                            let dialog = match &location {
+                                Some(location) => dialog.set_location(location),
+                                None => dialog,
+                            };
+                            let result = dialog
+                                .add_filter("PNG Image", &["png"])
+                                .add_filter("Binary Maze", &["maze"])
+                                .add_filter("JSON Maze", &["json"])
+                                .add_filter("SVG Image", &["svg"])
+                                .add_filter("PDF Document", &["pdf"])
+                                .add_filter("ASCII Text", &["txt"])
+                                .add_filter("Unicode Text", &["utf8"])
+                                .add_filter("Graphviz DOT", &["dot"])
+                                .add_filter("GraphML", &["graphml"])
+                                .add_filter("Animated GIF", &["gif"])
+                                .add_filter("Animated PNG", &["apng"])
+                                .show_save_single_file();
+                            callback(&result);
+                            sender
+                                .send(result)
+                                .expect("error sending show_save_single_file result to ui");
+                            drop(location)
+                        })
+                        .expect("Unable to open file_path dialog");
+                }
+            },
+        );
+    }
+
+    fn add_stats_panel(&mut self, ctx: &Context) {
+        let metrics = self.curr.as_ref().and_then(|e| e.get_status().get_metrics());
+        if metrics.is_none() {
+            return;
+        }
+
+        let metrics = metrics.unwrap();
+        if let Some(result) = self.metrics_file_dialog.check() {
+            match result {
+                Ok(Some(path)) => {
+                    self.metrics_path = path;
+                    let res = metrics
+                        .to_json()
+                        .and_then(|json| Ok(std::fs::write(&self.metrics_path, json)?));
+
+                    if let Err(e) = res {
+                        eprintln!("Could not export metrics: {}", e);
+                    }
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    eprintln!("Error selecting metrics path: {}", error)
+                }
+            }
+        }
+
+        egui::SidePanel::right("statistics").show(ctx, |ui| {
+            ui.label(RichText::new("Statistics").size(25.0));
+            Grid::new("statistics-grid").striped(true).show(ui, |ui| {
+                let solution = metrics
+                    .solution_length
+                    .map(|e| e.to_string())
+                    .unwrap_or_else(|| "None".to_string());
+
+                let rows = vec![
+                    ("Cells", metrics.cells.to_string()),
+                    ("Dead ends", metrics.dead_ends.to_string()),
+                    ("Junctions", metrics.junctions().to_string()),
+                    ("Junctions (3)", metrics.degree_counts[3].to_string()),
+                    ("Junctions (4)", metrics.degree_counts[4].to_string()),
+                    ("Loops", metrics.loops.to_string()),
+                    ("Components", metrics.components.to_string()),
+                    ("River factor", format!("{:.3}", metrics.river_factor)),
+                    ("Solution length", solution),
+                    ("Cells on solution", format!("{:.2}%", metrics.solution_fraction * 100.0)),
+                    ("Tortuosity", format!("{:.3}", metrics.tortuosity)),
+                ];
+
+                for (name, value) in rows {
+                    ui.label(name);
+                    ui.label(value);
+                    ui.end_row();
+                }
+            });
+
+            ui.add_space(10.0);
+            ui.label("Corridor lengths:");
+            Grid::new("corridor-grid").striped(true).show(ui, |ui| {
+                for (length, count) in &metrics.corridor_lengths {
+                    ui.label(length.to_string());
+                    ui.label(count.to_string());
+                    ui.end_row();
+                }
+            });
+
+            ui.add_space(10.0);
+            ui.add_enabled_ui(!self.metrics_file_dialog.is_open(), |ui| {
+                if ui.button("Export JSON").clicked() {
+                    let location = self
+                        .metrics_path
+                        .parent()
+                        .map(|location| location.to_path_buf());
+
+                    let temp = ctx.clone();
+                    self.metrics_file_dialog
+                        .with_callback(move |_| temp.request_repaint())
+                        .show(|sender, dialog, callback| {
+                            let dialog = match &location {
+                                Some(location) => dialog.set_location(location),
+                                None => dialog,
+                            };
+                            let result = dialog
+                                .add_filter("JSON", &["json"])
+                                .show_save_single_file();
+                            callback(&result);
+                            sender
+                                .send(result)
+                                .expect("error sending show_save_single_file result to ui");
+                            drop(location)
+                        })
+                        .expect("Unable to open file_path dialog");
+                }
+            });
+        });
+    }
+
+    fn add_gen_button(&mut self, ui: &mut Ui, ctx: &Context) {
+        let mut text = if self.loaded.is_some() { "Solve" } else { "Generate" };
+        if self.curr.is_some() {
+            let thread = self.curr.as_ref().unwrap();
+            let signal_sent = thread.exit_signal_sent();
+
+            let is_done = thread.get_status().is_done();
+            if !is_done {
+                if signal_sent {
+                    text = "Stopping...";
+                } else {
+                    text = "Stop";
+                }
+            }
+
+            if thread.is_finished() && signal_sent {
+                println!("Setting curr to None");
+                // If was done = User requested new maze
+                self.curr = if is_done {
+                    Some(self.start_thread(ctx))
+                } else {
+                    None
+                }
+            }
+        }
+
+        let res = ui.button(text);
+        if res.clicked() {
+            if self.curr.is_some() {
+                let thread = self.curr.as_ref().unwrap();
+                thread.terminate();
+                if self.seed_random {
+                    self.regenerate_seed();
+                }
+            } else {
+                self.curr = Some(self.start_thread(ctx));
+            }
+        }
+    }
+}
+
+impl App for MyApp {
+    fn update(&mut self, ctx: &Context, frame: &mut eframe::Frame) {
+        if !self.endless {
+            self.add_stats_panel(ctx);
+        }
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.heading("Maze Generator / Solver");
+            });
+            ui.horizontal(|ui| {
+                ui.vertical(|ui| {
+                    ui.label(RichText::new("Maze").size(25.0));
+                    self.add_seed_selector(ui, frame);
+                    self.add_size_selector(ui, frame);
+                    self.add_generator_selector(ui);
+                    self.add_solve_algorithm(ui);
+                    self.add_decimate_slider(ui);
+                    self.add_placement_selector(ui);
+                    self.add_endless_options(ui);
+                });
+                ui.add_space(30.0);
+                ui.vertical(|ui| {
+                    ui.label(RichText::new("Animation").size(25.0));
+                    self.add_speed_selector(ui, frame);
+                    self.add_show_animation(ui);
+
+                    let check = ui.checkbox(&mut self.show_debug, "Show debug?");
+                    if let Some(c) = self.curr.as_mut().filter(|_| check.changed()) {
+                        c.get_mut_data().set_show_debug(self.show_debug);
+                    }
+
+                    ui.checkbox(&mut self.validate, "Validate?");
+                    self.add_record_options(ui);
+                })
+            });
+
+            ui.add_space(10.0);
+            if self.endless {
+                ui.vertical_centered_justified(|ui| {
+                    if ui.button("New Seed").clicked() {
+                        self.regenerate_seed();
+                    }
+                    self.add_endless_view(ctx, ui);
+                });
+                return;
+            }
+
+            ui.vertical_centered_justified(|ui| {
+                self.add_gen_button(ui, ctx);
+                self.add_open_button(ui, ctx);
+                self.add_save_button(ui, ctx);
+            });
+
+            ui.vertical_centered_justified(|ui| {
+                if let Some(t) = &self.curr {
+                    let proc = t.get_canvas().get_canvas().get_progress();
+                    let readable_proc = (proc * 100.0 * 100.0).round() / 100.0;
+
+                    let mut text = format!("Generating: {}%", readable_proc);
+                    if proc == 1.0 {
+                        text = "Solving...".to_string();
+                    }
+
+                    if t.get_status().is_done() {
+                        text = format!("{:?} elapsed", t.get_status().get_time_elapsed().unwrap());
+                    }
+
+                    ui.label(RichText::new(text));
+                }
+                self.add_image(ctx, ui);
+            });
+        });
+    }
+}
+
+pub fn to_color32(color: &Color) -> Color32 {
+    let [r, g, b, a] = color.to_array();
+    Color32::from_rgba_unmultiplied(r, g, b, a)
+}
@@ -0,0 +1,68 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use anyhow::Result;
+use egui::Context;
+use maze_core::{
+    events::{canvas::Canvas, event::{MazeEvent, MazeObserver}},
+    tools::options::AnimOptions,
+};
+
+// Longest time in ms a single step waits when the speed is turned all the way down
+const MAX_WAIT_TIME: f64 = 1500.0;
+
+// Keeps the maze of the running thread up to date and slows the algorithms down so they can be watched
+pub struct AnimatedCanvas {
+    canvas: Canvas,
+    anim: AnimOptions,
+    frame_count: AtomicU64,
+    ctx: Context,
+}
+
+impl AnimatedCanvas {
+    pub fn new(ctx: &Context, anim: &AnimOptions) -> Self {
+        Self {
+            canvas: Canvas::default(),
+            anim: anim.clone(),
+            frame_count: AtomicU64::new(0),
+            ctx: ctx.clone(),
+        }
+    }
+
+    pub fn get_canvas(&self) -> &Canvas {
+        &self.canvas
+    }
+
+    fn on_step(&self) {
+        if !self.anim.show_anim() {
+            return;
+        }
+
+        let count = self.frame_count.fetch_add(1, Ordering::Relaxed) + 1;
+        let speed = self.anim.speed();
+        if !count.is_multiple_of((speed as u64).max(1)) {
+            return;
+        }
+
+        self.ctx.request_repaint();
+        if speed < 1.0 {
+            let millis = (1.0 - speed) * MAX_WAIT_TIME;
+            std::thread::sleep(Duration::from_millis(millis.floor() as u64));
+        }
+    }
+}
+
+impl MazeObserver for AnimatedCanvas {
+    fn on_event(&self, event: &MazeEvent) -> Result<()> {
+        self.canvas.apply(event);
+        match event {
+            MazeEvent::Step => self.on_step(),
+            MazeEvent::Progress(_) | MazeEvent::Loaded(_) | MazeEvent::Finished => self.ctx.request_repaint(),
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    collections::HashSet,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+};
+
+use anyhow::Result;
+use egui::*;
+use maze_core::{
+    chunks::{generate_tile, ChunkOptions, ChunkedMaze, TilePos},
+    point::{point::Point, size::Size, sparse_grid::SparseGrid},
+    tools::{consts::Maze, math::{numb_to_vec2, vec2_to_numb}, render::render_maze},
+};
+
+use super::app::to_color32;
+
+// Tiles this far outside of the view are kept, so panning back and forth doesn't generate them again
+const KEEP_MARGIN: i64 = 2;
+// Points the view moves per second while an arrow key is held
+const KEY_SPEED: f64 = 80.0;
+
+// Everything the last image was rendered from, it is only rendered again if one of them changes
+#[derive(Clone, Copy, PartialEq)]
+struct ViewKey {
+    x: f64,
+    y: f64,
+    zoom: usize,
+    width: usize,
+    height: usize,
+}
+
+// Pannable view of an endless maze. Tiles that come into view are generated on a worker thread,
+// until they are done the view shows walls in their place.
+pub struct EndlessViewer {
+    world: ChunkedMaze,
+    // World position of the top left corner of the view
+    x: f64,
+    y: f64,
+    texture: Option<TextureHandle>,
+    rendered: Option<ViewKey>,
+    // Tiles the worker was asked for that didn't arrive yet
+    pending: HashSet<TilePos>,
+    requests: Sender<TilePos>,
+    results: Receiver<(TilePos, Result<Maze>)>,
+}
+
+impl EndlessViewer {
+    pub fn new(ctx: &Context, seed: u64, options: ChunkOptions) -> Result<Self> {
+        let world = ChunkedMaze::new(seed, options)?;
+
+        // The worker stops as soon as the viewer is dropped and nobody can send requests anymore
+        let (requests, pending_requests) = mpsc::channel::<TilePos>();
+        let (finished, results) = mpsc::channel();
+        let ctx = ctx.clone();
+        thread::spawn(move || {
+            while let Ok(pos) = pending_requests.recv() {
+                if finished.send((pos, generate_tile(seed, &options, pos))).is_err() {
+                    break;
+                }
+                ctx.request_repaint();
+            }
+        });
+
+        Ok(Self {
+            world,
+            x: 0.0,
+            y: 0.0,
+            texture: None,
+            rendered: None,
+            pending: HashSet::new(),
+            requests,
+            results,
+        })
+    }
+
+    pub fn get_world(&self) -> &ChunkedMaze {
+        &self.world
+    }
+
+    pub fn is_for(&self, seed: u64, options: &ChunkOptions) -> bool {
+        self.world.get_seed() == seed && self.world.get_options() == *options
+    }
+
+    // Tile in the middle of the view
+    pub fn get_center_tile(&self, view: Vec2, zoom: usize) -> TilePos {
+        let x = self.x + view.x as f64 / zoom as f64 / 2.0;
+        let y = self.y + view.y as f64 / zoom as f64 / 2.0;
+        self.world.locate(x.floor() as i64, y.floor() as i64).0
+    }
+
+    pub fn show(&mut self, ctx: &Context, ui: &mut Ui, zoom: usize) -> Result<()> {
+        let left = ui.available_size_before_wrap();
+        if left.min_elem() < 1.0 {
+            return Ok(());
+        }
+
+        let zoom = zoom.max(1);
+        let img_width = left.x as usize;
+        let img_height = left.y as usize;
+
+        let mut changed = false;
+        while let Ok((pos, tile)) = self.results.try_recv() {
+            self.pending.remove(&pos);
+            self.world.insert_tile(pos, tile?);
+            changed = true;
+        }
+
+        // One more point on every side than fits, the view usually starts in the middle of a point
+        let view = Size::new(img_width / zoom + 2, img_height / zoom + 2);
+        let (origin_x, origin_y) = (self.x.floor() as i64, self.y.floor() as i64);
+
+        // Enough tiles for the view and the margin around it, the ones that weren't looked at the longest go first
+        let period = self.world.get_options().get_period() as i64;
+        let tiles_x = view.width as i64 / period + 2 + KEEP_MARGIN * 2;
+        let tiles_y = view.height as i64 / period + 2 + KEEP_MARGIN * 2;
+        self.world.set_capacity((tiles_x * tiles_y) as usize);
+
+        let key = ViewKey { x: self.x, y: self.y, zoom, width: img_width, height: img_height };
+        if changed || self.rendered != Some(key) || self.texture.is_none() {
+            let (region, missing) = self.world.loaded_region(origin_x, origin_y, view);
+            for pos in missing {
+                if self.pending.insert(pos) {
+                    self.requests.send(pos)?;
+                }
+            }
+
+            let pixels = render_maze(&region, &SparseGrid::new(view));
+            let fract_x = self.x - self.x.floor();
+            let fract_y = self.y - self.y.floor();
+            let mut img = ColorImage::new([img_width, img_height], Color32::BLACK);
+            for (pos, pixel) in img.pixels.iter_mut().enumerate() {
+                let Point { x, y } = numb_to_vec2(pos, img_width);
+                let view_x = (x as f64 / zoom as f64 + fract_x) as usize;
+                let view_y = (y as f64 / zoom as f64 + fract_y) as usize;
+
+                *pixel = to_color32(&pixels[vec2_to_numb(view_x, view_y, view.width)]);
+            }
+
+            let texture = self.texture.get_or_insert_with(|| {
+                ctx.load_texture("endless-texture", ColorImage::example(), Default::default())
+            });
+            texture.set(img, Default::default());
+            self.rendered = Some(key);
+        }
+
+        let texture = self.texture.as_ref().unwrap();
+        let res = ui.add(Image::new(texture.id(), texture.size_vec2()).sense(Sense::drag()));
+
+        let mut delta = -res.drag_delta() / zoom as f32;
+        let (dt, dir) = ctx.input(|i| {
+            let mut dir = Vec2::ZERO;
+            if i.key_down(Key::ArrowLeft) { dir.x -= 1.0; }
+            if i.key_down(Key::ArrowRight) { dir.x += 1.0; }
+            if i.key_down(Key::ArrowUp) { dir.y -= 1.0; }
+            if i.key_down(Key::ArrowDown) { dir.y += 1.0; }
+            (i.stable_dt, dir)
+        });
+        delta += dir * (dt as f64 * KEY_SPEED) as f32;
+
+        if delta != Vec2::ZERO {
+            self.x += delta.x as f64;
+            self.y += delta.y as f64;
+            ctx.request_repaint();
+        }
+
+        Ok(())
+    }
+}
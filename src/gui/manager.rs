@@ -0,0 +1,186 @@
+use std::{
+    sync::{Arc, RwLock},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use maze_core::{
+    analysis::metrics::MazeMetrics,
+    events::recorder::Recorder,
+    point::point::Point,
+    run::{load_maze, load_run, MazeRun},
+    solve::{d_star::WallToggles, placement::Placement, solve::SolveAlgorithm},
+    tools::{animation::is_animation_path, consts::MazeOptions, options::MazeData},
+};
+
+use super::canvas::AnimatedCanvas;
+
+// What the window and the thread of a run tell each other once it was started
+#[derive(Default)]
+pub struct RunStatus {
+    is_done: RwLock<bool>,
+    time_elapsed: RwLock<Option<Duration>>,
+    metrics: RwLock<Option<MazeMetrics>>,
+    save_requested: RwLock<Option<String>>,
+    wall_toggles: RwLock<Vec<Point>>,
+}
+
+impl RunStatus {
+    pub fn is_done(&self) -> bool {
+        *self.is_done.read().unwrap()
+    }
+
+    pub fn get_time_elapsed(&self) -> Option<Duration> {
+        *self.time_elapsed.read().unwrap()
+    }
+
+    pub fn get_metrics(&self) -> Option<MazeMetrics> {
+        self.metrics.read().unwrap().clone()
+    }
+
+    pub fn set_requested(&self, path: String) {
+        *self.save_requested.write().unwrap() = Some(path);
+    }
+
+    // Points the user clicked on while the maze is being solved, consumed by the D* Lite solver
+    pub fn push_wall_toggle(&self, p: Point) {
+        self.wall_toggles.write().unwrap().push(p);
+    }
+
+    fn finish(&self, time_elapsed: Duration, metrics: Option<MazeMetrics>) {
+        *self.time_elapsed.write().unwrap() = Some(time_elapsed);
+        *self.metrics.write().unwrap() = metrics;
+        *self.is_done.write().unwrap() = true;
+    }
+
+    fn take_requested(&self) -> Option<String> {
+        self.save_requested.write().unwrap().take()
+    }
+}
+
+impl WallToggles for RunStatus {
+    fn take_toggles(&self) -> Vec<Point> {
+        std::mem::take(&mut *self.wall_toggles.write().unwrap())
+    }
+}
+
+pub struct MazeThread {
+    data: MazeData,
+    status: Arc<RunStatus>,
+    canvas: Arc<AnimatedCanvas>,
+    algorithm: SolveAlgorithm,
+    thread: JoinHandle<Result<()>>
+}
+
+impl MazeThread {
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    pub fn exit_signal_sent(&self) -> bool {
+        self.data.should_exit()
+    }
+
+    pub fn get_options(&self) -> MazeOptions {
+        self.data.get_opt()
+    }
+
+    pub fn get_data(&self) -> &MazeData {
+        &self.data
+    }
+
+    pub fn get_status(&self) -> &RunStatus {
+        &self.status
+    }
+
+    pub fn get_canvas(&self) -> &AnimatedCanvas {
+        &self.canvas
+    }
+
+    pub fn get_algorithm(&self) -> SolveAlgorithm {
+        self.algorithm
+    }
+
+    pub fn get_mut_data(&mut self) -> &mut MazeData {
+        &mut self.data
+    }
+
+    pub fn terminate(&self) {
+        if self.exit_signal_sent() {
+            return;
+        }
+
+        self.data.set_should_exit(true);
+    }
+
+    // Generates a new maze, or solves the one stored at `path` if it is given
+    pub fn new(data: &MazeData, canvas: &Arc<AnimatedCanvas>, recorder: Option<Arc<Recorder>>, algorithm: SolveAlgorithm, path: Option<String>) -> Self {
+        let status = Arc::new(RunStatus::default());
+        let mut data = data.clone();
+        data.set_wall_toggles(status.clone());
+
+        let temp = data.clone();
+        let temp_status = status.clone();
+        Self {
+            data,
+            status,
+            canvas: canvas.clone(),
+            algorithm,
+            thread: thread::spawn(move || MazeThread::main_run(temp, temp_status, recorder, algorithm, path)),
+        }
+    }
+
+    pub fn main_run(data: MazeData, status: Arc<RunStatus>, recorder: Option<Arc<Recorder>>, algorithm: SolveAlgorithm, path: Option<String>) -> Result<()> {
+        let start_time = Instant::now();
+        let run = if let Some(path) = path {
+            let res = MazeThread::load(&data, &path, algorithm);
+            if let Err(e) = &res {
+                eprintln!("Could not open maze: {}", e);
+            }
+            res?
+        } else {
+            MazeRun::generate(&data, algorithm)?
+        };
+
+        status.finish(start_time.elapsed(), run.metrics.clone());
+        while !data.should_exit() {
+            if let Some(save_path) = status.take_requested() {
+                if !is_animation_path(&save_path) {
+                    run.save(&save_path)?;
+                } else if let Some(recorder) = &recorder {
+                    recorder.save(&save_path)?;
+                } else {
+                    eprintln!("Enable recording before generating to save an animation");
+                }
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        Ok(())
+    }
+
+    // The size and markers come from the file, everything else the solver needs from the options in the GUI.
+    // Saved runs (.json) already contain their solution and are shown as they are.
+    fn load(data: &MazeData, path: &str, algorithm: SolveAlgorithm) -> Result<MazeRun> {
+        if let Some(run) = load_run(path)? {
+            data.write_opt(&run.maze_options);
+            run.show(data)?;
+            return Ok(run);
+        }
+
+        let (maze, loaded) = load_maze(path)?;
+
+        let mut opt = data.get_opt();
+        opt.size = loaded.size;
+        opt.decimate = 0;
+        opt.generator = None;
+        if let Placement::Custom(..) | Placement::Openings = loaded.placement {
+            opt.placement = loaded.placement;
+        }
+        data.write_opt(&opt);
+
+        MazeRun::load(data, maze, algorithm)
+    }
+}
@@ -0,0 +1,6 @@
+pub mod app;
+pub mod canvas;
+pub mod endless;
+pub mod manager;
+
+pub use app::run;
\ No newline at end of file
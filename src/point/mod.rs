@@ -1,3 +0,0 @@
-pub mod point_state;
-pub mod point;
-pub mod direction;
\ No newline at end of file
@@ -1,5 +0,0 @@
-mod a_star;
-mod node;
-
-pub use node::*;
-pub use a_star::*;
\ No newline at end of file
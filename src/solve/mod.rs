@@ -1,2 +0,0 @@
-pub mod a_star;
-pub mod solve;
\ No newline at end of file
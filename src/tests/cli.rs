@@ -0,0 +1,130 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use clap::Parser;
+
+use maze_core::analysis::metrics::MazeMetrics;
+
+use crate::cli::{run, Cli};
+
+fn run_args(args: &[&str]) {
+    let cli = Cli::try_parse_from(args).unwrap();
+    run(cli.command.unwrap()).unwrap();
+}
+
+// Every test gets its own directory, so they can run at the same time
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("maze-cli-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn path(dir: &Path, name: &str) -> String {
+    dir.join(name).to_string_lossy().to_string()
+}
+
+// 31x31 maze without a solution to run the other commands on
+fn generated(dir: &Path) -> String {
+    let maze = path(dir, "maze.png");
+    run_args(&["maze-solver", "generate", "--size", "31", "--seed", "4", "--output", &maze]);
+    maze
+}
+
+#[test]
+pub fn generate_command() {
+    let dir = temp_dir("generate");
+    let img = image::open(generated(&dir)).unwrap();
+    assert_eq!(img.width(), 31);
+
+    run_args(&["maze-solver", "generate", "--size", "41x21", "--seed", "3", "--output", &path(&dir, "rect.png")]);
+    let img = image::open(path(&dir, "rect.png")).unwrap();
+    assert_eq!((img.width(), img.height()), (41, 21));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn solve_command() {
+    let dir = temp_dir("solve");
+    let maze = generated(&dir);
+
+    run_args(&["maze-solver", "solve", "--input", &maze, "--algorithm", "a-star", "--output", &path(&dir, "solved.png")]);
+    assert!(fs::metadata(path(&dir, "solved.png")).is_ok());
+
+    run_args(&["maze-solver", "solve", "--size", "21", "--seed", "2", "--record", &path(&dir, "run.gif"), "--frame-skip", "50", "--output", &path(&dir, "recorded.png")]);
+    let gif = image::open(path(&dir, "run.gif")).unwrap();
+    assert_eq!(gif.width(), 21 * 4);
+
+    run_args(&["maze-solver", "generate", "--size", "41x21", "--seed", "3", "--output", &path(&dir, "rect.png")]);
+    run_args(&["maze-solver", "solve", "--input", &path(&dir, "rect.png"), "--output", &path(&dir, "rect.json")]);
+    assert!(fs::read_to_string(path(&dir, "rect.json")).unwrap().contains("\"height\": 21"));
+
+    run_args(&["maze-solver", "solve", "--size", "41", "--seed", "5", "--bit-packed", "--output", &path(&dir, "bits.maze")]);
+    let json = path(&dir, "bits.json");
+    run_args(&["maze-solver", "solve", "--size", "41", "--seed", "5", "--bit-packed", "--output", &json]);
+    assert!(fs::read_to_string(json).unwrap().contains("\"generator\": \"hunt_and_kill_scan\""));
+
+    run_args(&["maze-solver", "solve", "--size", "61x41", "--seed", "5", "--generator", "parallel", "--threads", "3", "--output", &path(&dir, "parallel.json")]);
+    assert!(fs::read_to_string(path(&dir, "parallel.json")).unwrap().contains("\"threads\": 3"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn analyze_command() {
+    let dir = temp_dir("analyze");
+    let maze = generated(&dir);
+
+    run_args(&["maze-solver", "analyze", "--input", &maze, "--output", &path(&dir, "metrics.json")]);
+    let metrics: MazeMetrics = serde_json::from_str(&fs::read_to_string(path(&dir, "metrics.json")).unwrap()).unwrap();
+    assert_eq!(metrics.cells, 15 * 15);
+    assert_eq!(metrics.loops, 0);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn convert_command() {
+    let dir = temp_dir("convert");
+    let maze = generated(&dir);
+
+    run_args(&["maze-solver", "convert", "--input", &maze, "--output", &path(&dir, "maze.graphml")]);
+    assert!(fs::read_to_string(path(&dir, "maze.graphml")).unwrap().contains("<graphml"));
+
+    run_args(&["maze-solver", "solve", "--input", &maze, "--output", &path(&dir, "solved.png")]);
+    run_args(&["maze-solver", "convert", "--input", &path(&dir, "solved.png"), "--output", &path(&dir, "maze.svg")]);
+    assert!(fs::read_to_string(path(&dir, "maze.svg")).unwrap().starts_with("<svg"));
+
+    run_args(&["maze-solver", "convert", "--input", &maze, "--output", &path(&dir, "maze.utf8")]);
+    run_args(&["maze-solver", "convert", "--input", &path(&dir, "maze.utf8"), "--output", &path(&dir, "maze.txt")]);
+    assert_eq!(fs::read_to_string(path(&dir, "maze.txt")).unwrap().lines().count(), 31);
+
+    run_args(&["maze-solver", "convert", "--input", &maze, "--output", &path(&dir, "maze.maze")]);
+    run_args(&["maze-solver", "solve", "--input", &path(&dir, "maze.maze"), "--output", &path(&dir, "from_binary.png")]);
+    assert_eq!(image::open(path(&dir, "from_binary.png")).unwrap().width(), 31);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn pdf_command() {
+    let dir = temp_dir("pdf");
+
+    run_args(&["maze-solver", "pdf", "--size", "21", "--seed", "1", "--count", "2", "--page-size", "letter", "--output", &path(&dir, "book.pdf")]);
+    assert!(fs::read(path(&dir, "book.pdf")).unwrap().starts_with(b"%PDF"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+pub fn parse_arguments() {
+    assert!(Cli::try_parse_from(["maze-solver"]).unwrap().command.is_none());
+    assert!(Cli::try_parse_from(["maze-solver", "solve", "-a", "multi-agent", "-o", "out.png"]).is_ok());
+    assert!(Cli::try_parse_from(["maze-solver", "solve", "-a", "bogus", "-o", "out.png"]).is_err());
+    assert!(Cli::try_parse_from(["maze-solver", "generate"]).is_err());
+    for size in ["0", "1", "3", "2x3", "bogus"] {
+        assert!(Cli::try_parse_from(["maze-solver", "generate", "--size", size, "-o", "out.png"]).is_err());
+    }
+    assert!(Cli::try_parse_from(["maze-solver", "generate", "--size", "4x2", "-o", "out.png"]).is_ok());
+    assert!(Cli::try_parse_from(["maze-solver", "generate", "-g", "bogus", "-o", "out.png"]).is_err());
+    assert!(Cli::try_parse_from(["maze-solver", "solve", "--bit-packed", "-i", "in.png", "-o", "out.png"]).is_err());
+}
@@ -1,4 +1,2 @@
 #[cfg(test)]
-pub mod generate;
-#[cfg(test)]
-pub mod solve;
\ No newline at end of file
+pub mod cli;
\ No newline at end of file
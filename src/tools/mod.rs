@@ -1,7 +0,0 @@
-pub mod consts;
-pub mod window;
-pub mod math;
-pub mod direction_data;
-pub mod matrix;
-pub mod options;
-pub mod image;
\ No newline at end of file